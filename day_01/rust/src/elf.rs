@@ -1,29 +1,163 @@
-#[derive(Debug, Clone)]
+use std::{error, fmt};
+
+#[cfg(not(any(feature = "calories-u64", feature = "calories-u128")))]
+pub type Calories = u32;
+
+#[cfg(all(feature = "calories-u64", not(feature = "calories-u128")))]
+pub type Calories = u64;
+
+#[cfg(feature = "calories-u128")]
+pub type Calories = u128;
+
+#[derive(Debug, PartialEq)]
+pub struct CaloriesOverflowError {
+    calories: Calories,
+    added_calories: Calories,
+}
+
+impl CaloriesOverflowError {
+    pub fn build(calories: Calories, added_calories: Calories) -> Self {
+        CaloriesOverflowError {
+            calories,
+            added_calories,
+        }
+    }
+}
+
+impl fmt::Display for CaloriesOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "calories overflow error, adding {} to {} exceeds the maximum of {}",
+            self.added_calories,
+            self.calories,
+            Calories::MAX
+        )
+    }
+}
+
+impl error::Error for CaloriesOverflowError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snack {
+    calories: Calories,
+    line_index: usize,
+}
+
+impl Snack {
+    pub fn build(calories: Calories, line_index: usize) -> Self {
+        Snack {
+            calories,
+            line_index,
+        }
+    }
+
+    pub fn get_calories(&self) -> Calories {
+        self.calories
+    }
+
+    pub fn get_line_index(&self) -> usize {
+        self.line_index
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Elf {
-    calories: u32,
+    snacks: Vec<Snack>,
+    calories: Calories,
 }
 
 impl Elf {
     pub fn new() -> Self {
-        Elf { calories: 0 }
+        Elf::default()
     }
 
     pub fn has_calories(&self) -> bool {
         self.calories != 0
     }
 
-    pub fn add_calories(&mut self, calories: u32) {
-        self.calories += calories
+    pub fn has_snacks(&self) -> bool {
+        !self.snacks.is_empty()
+    }
+
+    pub fn try_add_snack(&mut self, snack: Snack) -> Result<(), CaloriesOverflowError> {
+        self.calories = match self.calories.checked_add(snack.get_calories()) {
+            Some(calories) => calories,
+            None => {
+                return Err(CaloriesOverflowError::build(
+                    self.calories,
+                    snack.get_calories(),
+                ))
+            }
+        };
+
+        self.snacks.push(snack);
+
+        Ok(())
     }
 
-    pub fn get_calories(&self) -> u32 {
+    pub fn get_calories(&self) -> Calories {
         self.calories
     }
+
+    pub fn get_snacks(&self) -> &[Snack] {
+        &self.snacks
+    }
+
+    pub fn get_number_of_snacks(&self) -> usize {
+        self.snacks.len()
+    }
+
+    pub fn get_largest_snack(&self) -> Option<&Snack> {
+        self.snacks
+            .iter()
+            .rev()
+            .max_by_key(|snack| snack.get_calories())
+    }
+
+    pub fn get_mean_calories(&self) -> Option<f64> {
+        if self.snacks.is_empty() {
+            return None;
+        }
+
+        Some(self.calories as f64 / self.snacks.len() as f64)
+    }
+
+    pub fn get_median_calories(&self) -> Option<f64> {
+        let mut calories_list: Vec<Calories> = self
+            .snacks
+            .iter()
+            .map(|snack| snack.get_calories())
+            .collect();
+        calories_list.sort();
+
+        let middle = calories_list.len() / 2;
+
+        match calories_list.len() {
+            0 => None,
+            length if length % 2 == 0 => {
+                Some((calories_list[middle - 1] as f64 + calories_list[middle] as f64) / 2.0)
+            }
+            _ => Some(calories_list[middle] as f64),
+        }
+    }
+
+    pub fn get_deciding_snack(&self, calories_to_beat: Calories) -> Option<&Snack> {
+        let mut calories = 0;
+
+        self.snacks.iter().find(|snack| {
+            calories += snack.get_calories();
+            calories > calories_to_beat
+        })
+    }
 }
 
 impl PartialEq for Elf {
     fn eq(&self, other: &Self) -> bool {
-        self.calories == other.calories
+        self.snacks
+            .iter()
+            .map(|snack| snack.get_calories())
+            .eq(other.snacks.iter().map(|snack| snack.get_calories()))
     }
 }
 
@@ -31,6 +165,15 @@ impl PartialEq for Elf {
 mod tests {
     use super::*;
 
+    fn build_elf(input_calories: Vec<Calories>) -> Elf {
+        let mut elf = Elf::new();
+        for (index, calories) in input_calories.into_iter().enumerate() {
+            elf.try_add_snack(Snack::build(calories, index + 1))
+                .unwrap();
+        }
+        elf
+    }
+
     #[test]
     fn test_empty_elf_no_calories() {
         let elf = Elf::new();
@@ -43,35 +186,132 @@ mod tests {
         assert!(!elf.has_calories());
     }
 
+    #[test]
+    fn test_empty_elf_has_no_snacks() {
+        let elf = Elf::new();
+        assert!(!elf.has_snacks());
+    }
+
+    #[test]
+    fn test_zero_calories_elf_has_snacks() {
+        let elf = build_elf(vec![0]);
+        assert!(elf.has_snacks());
+        assert!(!elf.has_calories());
+    }
+
     #[test]
     fn test_elf_one_calories() {
-        let mut elf = Elf::new();
-        elf.add_calories(100);
+        let elf = build_elf(vec![100]);
         assert_eq!(elf.get_calories(), 100);
     }
 
     #[test]
     fn test_elf_one_calories_has_calories() {
-        let mut elf = Elf::new();
-        elf.add_calories(200);
+        let elf = build_elf(vec![200]);
         assert!(elf.has_calories());
     }
 
     #[test]
     fn test_elf_multiple_calories() {
-        let mut elf = Elf::new();
-        elf.add_calories(100);
-        elf.add_calories(200);
-        elf.add_calories(300);
+        let elf = build_elf(vec![100, 200, 300]);
         assert_eq!(elf.get_calories(), 600);
     }
 
     #[test]
     fn test_elf_multiple_calories_has_calories() {
-        let mut elf = Elf::new();
-        elf.add_calories(100);
-        elf.add_calories(200);
-        elf.add_calories(300);
+        let elf = build_elf(vec![100, 200, 300]);
         assert!(elf.has_calories());
     }
+
+    #[test]
+    fn test_elf_snacks() {
+        let elf = build_elf(vec![100, 200, 300]);
+
+        let expected_snacks = vec![
+            Snack::build(100, 1),
+            Snack::build(200, 2),
+            Snack::build(300, 3),
+        ];
+
+        assert_eq!(elf.get_snacks(), expected_snacks);
+        assert_eq!(elf.get_number_of_snacks(), 3);
+    }
+
+    #[test]
+    fn test_empty_elf_statistics() {
+        let elf = Elf::new();
+        assert_eq!(elf.get_number_of_snacks(), 0);
+        assert_eq!(elf.get_largest_snack(), None);
+        assert_eq!(elf.get_mean_calories(), None);
+        assert_eq!(elf.get_median_calories(), None);
+    }
+
+    #[test]
+    fn test_elf_largest_snack() {
+        let elf = build_elf(vec![300, 100, 500, 200, 500]);
+        assert_eq!(elf.get_largest_snack(), Some(&Snack::build(500, 3)));
+    }
+
+    #[test]
+    fn test_elf_mean_calories() {
+        let elf = build_elf(vec![100, 200, 400]);
+        assert_eq!(elf.get_mean_calories(), Some(700.0 / 3.0));
+    }
+
+    #[test]
+    fn test_elf_median_calories_odd_number_of_snacks() {
+        let elf = build_elf(vec![400, 100, 200]);
+        assert_eq!(elf.get_median_calories(), Some(200.0));
+    }
+
+    #[test]
+    fn test_elf_median_calories_even_number_of_snacks() {
+        let elf = build_elf(vec![400, 100, 200, 300]);
+        assert_eq!(elf.get_median_calories(), Some(250.0));
+    }
+
+    #[test]
+    fn test_elf_deciding_snack() {
+        let elf = build_elf(vec![100, 200, 300]);
+        assert_eq!(elf.get_deciding_snack(250), Some(&Snack::build(200, 2)));
+        assert_eq!(elf.get_deciding_snack(300), Some(&Snack::build(300, 3)));
+        assert_eq!(elf.get_deciding_snack(600), None);
+    }
+
+    #[test]
+    fn test_elves_with_same_calories_but_different_snacks_differ() {
+        let elf_1 = build_elf(vec![100, 200]);
+        let elf_2 = build_elf(vec![300]);
+        assert_ne!(elf_1, elf_2);
+    }
+
+    #[test]
+    fn test_elves_with_same_snacks_on_different_lines_are_equal() {
+        let mut elf_1 = Elf::new();
+        elf_1.try_add_snack(Snack::build(100, 1)).unwrap();
+        let mut elf_2 = Elf::new();
+        elf_2.try_add_snack(Snack::build(100, 7)).unwrap();
+        assert_eq!(elf_1, elf_2);
+    }
+
+    #[test]
+    fn test_elf_max_calories() {
+        let elf = build_elf(vec![Calories::MAX - 1, 1]);
+        assert_eq!(elf.get_calories(), Calories::MAX);
+    }
+
+    #[test]
+    fn test_elf_calories_overflow() {
+        let mut elf = build_elf(vec![Calories::MAX - 1, 1]);
+
+        assert_eq!(
+            elf.try_add_snack(Snack::build(5, 3)),
+            Err(CaloriesOverflowError {
+                calories: Calories::MAX,
+                added_calories: 5,
+            })
+        );
+        assert_eq!(elf.get_calories(), Calories::MAX);
+        assert_eq!(elf.get_number_of_snacks(), 2);
+    }
 }
@@ -0,0 +1,307 @@
+use crate::elf::{Calories, CaloriesOverflowError};
+use std::{
+    fs,
+    io::{self, Write},
+    path,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct GeneratedAnswers {
+    number_of_elves: usize,
+    max_calories_from_one_elf: Calories,
+    max_calories_from_three_elves: Calories,
+}
+
+impl GeneratedAnswers {
+    pub fn get_number_of_elves(&self) -> usize {
+        self.number_of_elves
+    }
+
+    pub fn get_max_calories_from_one_elf(&self) -> Calories {
+        self.max_calories_from_one_elf
+    }
+
+    pub fn get_max_calories_from_three_elves(&self) -> Calories {
+        self.max_calories_from_three_elves
+    }
+}
+
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn build(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_in_range<T: Into<u128>>(&mut self, min: T, max: T) -> u128 {
+        let (min, max) = (min.into(), max.into());
+        let value = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+
+        match (max - min).checked_add(1) {
+            Some(range) => min + value % range,
+            None => value,
+        }
+    }
+}
+
+pub struct InventoryGenerator {
+    seed: u64,
+    number_of_elves: usize,
+    snacks_per_elf: (usize, usize),
+    calories_per_snack: (Calories, Calories),
+}
+
+impl Default for InventoryGenerator {
+    fn default() -> Self {
+        InventoryGenerator::build(0)
+    }
+}
+
+impl InventoryGenerator {
+    pub fn build(seed: u64) -> Self {
+        InventoryGenerator {
+            seed,
+            number_of_elves: 1000,
+            snacks_per_elf: (1, 15),
+            calories_per_snack: (1000, 10000),
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_number_of_elves(mut self, number_of_elves: usize) -> Self {
+        self.number_of_elves = number_of_elves;
+        self
+    }
+
+    pub fn with_snacks_per_elf(mut self, min_snacks: usize, max_snacks: usize) -> Self {
+        let min_snacks = min_snacks.max(1);
+        self.snacks_per_elf = (min_snacks, max_snacks.max(min_snacks));
+        self
+    }
+
+    pub fn with_calories_per_snack(
+        mut self,
+        min_calories: Calories,
+        max_calories: Calories,
+    ) -> Self {
+        self.calories_per_snack = (min_calories, max_calories.max(min_calories));
+        self
+    }
+
+    pub fn try_write<W: Write>(&self, writer: W) -> Result<GeneratedAnswers, io::Error> {
+        let mut writer = io::BufWriter::new(writer);
+        let mut random = SplitMix64::build(self.seed);
+        let mut top_three_calories: [Calories; 3] = [0; 3];
+
+        for elf_index in 0..self.number_of_elves {
+            if elf_index > 0 {
+                writeln!(writer)?;
+            }
+
+            let number_of_snacks =
+                random.next_in_range(self.snacks_per_elf.0 as u64, self.snacks_per_elf.1 as u64);
+
+            let mut elf_calories: Calories = 0;
+
+            for _ in 0..number_of_snacks {
+                let calories = random
+                    .next_in_range(self.calories_per_snack.0, self.calories_per_snack.1)
+                    as Calories;
+
+                elf_calories = Self::try_add_calories(elf_calories, calories)?;
+
+                writeln!(writer, "{}", calories)?;
+            }
+
+            Self::insert_into_top_three(&mut top_three_calories, elf_calories);
+        }
+
+        writer.flush()?;
+
+        let mut max_calories_from_three_elves: Calories = 0;
+        for calories in top_three_calories {
+            max_calories_from_three_elves =
+                Self::try_add_calories(max_calories_from_three_elves, calories)?;
+        }
+
+        Ok(GeneratedAnswers {
+            number_of_elves: self.number_of_elves,
+            max_calories_from_one_elf: top_three_calories[0],
+            max_calories_from_three_elves,
+        })
+    }
+
+    pub fn try_write_file(&self, file_name: &str) -> Result<GeneratedAnswers, io::Error> {
+        let file = fs::File::create(path::Path::new(file_name))?;
+        self.try_write(file)
+    }
+
+    pub fn try_generate(&self) -> Result<(String, GeneratedAnswers), io::Error> {
+        let mut input = Vec::new();
+        let answers = self.try_write(&mut input)?;
+
+        match String::from_utf8(input) {
+            Ok(input) => Ok((input, answers)),
+            Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        }
+    }
+
+    fn try_add_calories(
+        calories: Calories,
+        added_calories: Calories,
+    ) -> Result<Calories, io::Error> {
+        match calories.checked_add(added_calories) {
+            Some(calories) => Ok(calories),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                CaloriesOverflowError::build(calories, added_calories),
+            )),
+        }
+    }
+
+    fn insert_into_top_three(top_three_calories: &mut [Calories; 3], calories: Calories) {
+        if let Some(position) = top_three_calories
+            .iter()
+            .position(|top_calories| calories > *top_calories)
+        {
+            top_three_calories[position..].rotate_right(1);
+            top_three_calories[position] = calories;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        get_max_calories_from_one_and_three_elves_from_reader,
+        get_max_calories_from_one_elf_from_reader, get_max_calories_from_three_elves_from_reader,
+        ElvesParser, ElvesParsingError,
+    };
+
+    fn build_random_generator(seed: u64) -> InventoryGenerator {
+        let mut random = SplitMix64::build(seed);
+
+        let number_of_elves = random.next_in_range(1_u32, 200) as usize;
+        let min_snacks = random.next_in_range(1_u32, 5) as usize;
+        let max_snacks = min_snacks + random.next_in_range(0_u32, 10) as usize;
+        let min_calories = random.next_in_range(0_u32, 5000) as Calories;
+        let max_calories = min_calories + random.next_in_range(0_u32, 50000) as Calories;
+
+        InventoryGenerator::build(seed)
+            .with_number_of_elves(number_of_elves)
+            .with_snacks_per_elf(min_snacks, max_snacks)
+            .with_calories_per_snack(min_calories, max_calories)
+    }
+
+    #[test]
+    fn test_generator_is_reproducible() {
+        let generator = InventoryGenerator::build(42).with_number_of_elves(50);
+        assert_eq!(
+            generator.try_generate().unwrap(),
+            generator.try_generate().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generators_with_different_seeds_differ() {
+        let (input_1, _) = InventoryGenerator::build(1).try_generate().unwrap();
+        let (input_2, _) = InventoryGenerator::build(2).try_generate().unwrap();
+        assert_ne!(input_1, input_2);
+    }
+
+    #[test]
+    fn test_generated_inventory_shape() {
+        let generator = InventoryGenerator::build(7)
+            .with_number_of_elves(30)
+            .with_snacks_per_elf(2, 4)
+            .with_calories_per_snack(100, 200);
+
+        let (input, answers) = generator.try_generate().unwrap();
+        let elves = ElvesParser::build_from_reader(input.as_bytes())
+            .try_get_elves()
+            .unwrap();
+
+        assert_eq!(elves.len(), 30);
+        assert_eq!(answers.get_number_of_elves(), 30);
+
+        for elf in elves {
+            assert!((2..=4).contains(&elf.get_number_of_snacks()));
+            for snack in elf.get_snacks() {
+                assert!((100..=200).contains(&snack.get_calories()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inventory_without_elves() {
+        let (input, answers) = InventoryGenerator::build(3)
+            .with_number_of_elves(0)
+            .try_generate()
+            .unwrap();
+
+        assert_eq!(input, "");
+        assert_eq!(answers.get_max_calories_from_one_elf(), 0);
+        assert_eq!(
+            get_max_calories_from_one_elf_from_reader(input.as_bytes()),
+            Err(ElvesParsingError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_generated_inventory_overflow() {
+        let generator = InventoryGenerator::build(5)
+            .with_number_of_elves(1)
+            .with_snacks_per_elf(2, 2)
+            .with_calories_per_snack(Calories::MAX, Calories::MAX);
+
+        assert_eq!(
+            generator.try_generate().unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_generated_answers_match_results() {
+        for seed in 0..100 {
+            let (input, answers) = build_random_generator(seed).try_generate().unwrap();
+
+            assert_eq!(
+                get_max_calories_from_one_elf_from_reader(input.as_bytes()),
+                Ok(answers.get_max_calories_from_one_elf()),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                get_max_calories_from_three_elves_from_reader(input.as_bytes()),
+                Ok(answers.get_max_calories_from_three_elves()),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                get_max_calories_from_one_and_three_elves_from_reader(input.as_bytes()),
+                Ok((
+                    answers.get_max_calories_from_one_elf(),
+                    answers.get_max_calories_from_three_elves()
+                )),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
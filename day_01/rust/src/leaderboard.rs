@@ -0,0 +1,139 @@
+use crate::elf::Calories;
+use std::{cmp, collections::BinaryHeap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    elf_index: usize,
+    calories: Calories,
+}
+
+impl LeaderboardEntry {
+    pub fn build(elf_index: usize, calories: Calories) -> Self {
+        LeaderboardEntry {
+            elf_index,
+            calories,
+        }
+    }
+
+    pub fn get_elf_index(&self) -> usize {
+        self.elf_index
+    }
+
+    pub fn get_calories(&self) -> Calories {
+        self.calories
+    }
+}
+
+// entries with more calories rank higher, on a tie the elf that came first ranks higher
+impl Ord for LeaderboardEntry {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then_with(|| other.elf_index.cmp(&self.elf_index))
+    }
+}
+
+impl PartialOrd for LeaderboardEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone)]
+pub struct Leaderboard {
+    size: usize,
+    entries: BinaryHeap<cmp::Reverse<LeaderboardEntry>>,
+}
+
+impl Leaderboard {
+    pub fn build(size: usize) -> Self {
+        Leaderboard {
+            size,
+            entries: BinaryHeap::with_capacity(size + 1),
+        }
+    }
+
+    pub fn add(&mut self, entry: LeaderboardEntry) {
+        if self.entries.len() < self.size {
+            self.entries.push(cmp::Reverse(entry));
+            return;
+        }
+
+        if let Some(mut lowest_entry) = self.entries.peek_mut() {
+            if entry > lowest_entry.0 {
+                *lowest_entry = cmp::Reverse(entry);
+            }
+        }
+    }
+
+    pub fn get_entries(&self) -> Vec<LeaderboardEntry> {
+        self.entries
+            .clone()
+            .into_sorted_vec()
+            .into_iter()
+            .map(|entry| entry.0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_leaderboard(size: usize, calories_list: Vec<Calories>) -> Leaderboard {
+        let mut leaderboard = Leaderboard::build(size);
+        for (elf_index, calories) in calories_list.into_iter().enumerate() {
+            leaderboard.add(LeaderboardEntry::build(elf_index, calories));
+        }
+        leaderboard
+    }
+
+    #[test]
+    fn test_empty_leaderboard() {
+        let leaderboard = build_leaderboard(3, vec![]);
+        assert_eq!(leaderboard.get_entries(), vec![]);
+    }
+
+    #[test]
+    fn test_zero_sized_leaderboard() {
+        let leaderboard = build_leaderboard(0, vec![100, 200, 300]);
+        assert_eq!(leaderboard.get_entries(), vec![]);
+    }
+
+    #[test]
+    fn test_leaderboard_with_less_entries_than_size() {
+        let leaderboard = build_leaderboard(3, vec![100, 300]);
+
+        let expected_entries = vec![
+            LeaderboardEntry::build(1, 300),
+            LeaderboardEntry::build(0, 100),
+        ];
+
+        assert_eq!(leaderboard.get_entries(), expected_entries);
+    }
+
+    #[test]
+    fn test_leaderboard_keeps_highest_entries() {
+        let leaderboard = build_leaderboard(3, vec![400, 100, 700, 200, 500, 300]);
+
+        let expected_entries = vec![
+            LeaderboardEntry::build(2, 700),
+            LeaderboardEntry::build(4, 500),
+            LeaderboardEntry::build(0, 400),
+        ];
+
+        assert_eq!(leaderboard.get_entries(), expected_entries);
+    }
+
+    #[test]
+    fn test_leaderboard_prefers_first_elf_on_tie() {
+        let leaderboard = build_leaderboard(2, vec![100, 300, 100, 300, 300]);
+
+        let expected_entries = vec![
+            LeaderboardEntry::build(1, 300),
+            LeaderboardEntry::build(3, 300),
+        ];
+
+        assert_eq!(leaderboard.get_entries(), expected_entries);
+    }
+}
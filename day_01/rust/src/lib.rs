@@ -1,46 +1,159 @@
 mod elf;
+mod generator;
+mod leaderboard;
+#[cfg(feature = "parallel")]
+mod parallel;
 mod parser;
+mod stats;
+mod tracker;
 
-use parser::ElvesParser;
+pub use elf::{Calories, CaloriesOverflowError, Elf, Snack};
+pub use generator::{GeneratedAnswers, InventoryGenerator};
+pub use leaderboard::LeaderboardEntry;
+pub use parser::{ElfGrouping, ElvesParser, ElvesParsingError};
+#[cfg(feature = "parallel")]
+pub use parser::{ElvesChunk, ElvesChunks};
+pub use stats::{HistogramBin, InventoryStatistics};
+pub use tracker::CalorieTracker;
 
-pub fn get_max_calories_from_one_elf(file_name: &str) -> u32 {
-    let mut elves_parser = ElvesParser::build(file_name);
-    let elves = match elves_parser.try_get_elves() {
-        Ok(elves) => elves,
-        Err(_) => return 0,
-    };
+#[cfg(not(feature = "parallel"))]
+use leaderboard::Leaderboard;
+use std::io::BufRead;
 
-    elves
-        .iter()
-        .map(|elf| elf.get_calories())
-        .max()
-        .unwrap_or_default()
+pub fn get_max_calories_from_one_elf(file_name: &str) -> Result<Calories, ElvesParsingError> {
+    get_max_calories(ElvesParser::build(file_name), 1)
 }
 
-pub fn get_max_calories_from_three_elves(file_name: &str) -> u32 {
-    let mut elves_parser = ElvesParser::build(file_name);
-    let elves = match elves_parser.try_get_elves() {
-        Ok(elves) => elves,
-        Err(_) => return 0,
-    };
+pub fn get_max_calories_from_one_elf_from_reader<R: BufRead>(
+    reader: R,
+) -> Result<Calories, ElvesParsingError> {
+    get_max_calories(ElvesParser::build_from_reader(reader), 1)
+}
+
+pub fn get_max_calories_from_three_elves(file_name: &str) -> Result<Calories, ElvesParsingError> {
+    get_max_calories(ElvesParser::build(file_name), 3)
+}
+
+pub fn get_max_calories_from_three_elves_from_reader<R: BufRead>(
+    reader: R,
+) -> Result<Calories, ElvesParsingError> {
+    get_max_calories(ElvesParser::build_from_reader(reader), 3)
+}
+
+pub fn top_n_calories(
+    file_name: &str,
+    n: usize,
+) -> Result<Vec<LeaderboardEntry>, ElvesParsingError> {
+    get_top_n_calories(ElvesParser::build(file_name), n)
+}
+
+pub fn top_n_calories_from_reader<R: BufRead>(
+    reader: R,
+    n: usize,
+) -> Result<Vec<LeaderboardEntry>, ElvesParsingError> {
+    get_top_n_calories(ElvesParser::build_from_reader(reader), n)
+}
+
+pub fn get_max_calories_from_one_and_three_elves(
+    file_name: &str,
+) -> Result<(Calories, Calories), ElvesParsingError> {
+    get_max_calories_from_one_and_three(ElvesParser::build(file_name))
+}
+
+pub fn get_max_calories_from_one_and_three_elves_from_reader<R: BufRead>(
+    reader: R,
+) -> Result<(Calories, Calories), ElvesParsingError> {
+    get_max_calories_from_one_and_three(ElvesParser::build_from_reader(reader))
+}
+
+pub fn get_inventory_statistics(file_name: &str) -> Result<InventoryStatistics, ElvesParsingError> {
+    InventoryStatistics::try_build(&ElvesParser::build(file_name).try_get_elves()?)
+}
 
-    let mut calories_list: Vec<u32> = elves.iter().map(|elf| elf.get_calories()).collect();
-    calories_list.sort();
-    calories_list.reverse();
-    calories_list.iter().take(3).sum()
+pub fn get_inventory_statistics_from_reader<R: BufRead>(
+    reader: R,
+) -> Result<InventoryStatistics, ElvesParsingError> {
+    InventoryStatistics::try_build(&ElvesParser::build_from_reader(reader).try_get_elves()?)
+}
+
+fn get_max_calories(
+    elves_parser: ElvesParser,
+    number_of_elves: usize,
+) -> Result<Calories, ElvesParsingError> {
+    try_sum_calories(&get_top_n_calories(elves_parser, number_of_elves)?)
+}
+
+fn get_max_calories_from_one_and_three(
+    elves_parser: ElvesParser,
+) -> Result<(Calories, Calories), ElvesParsingError> {
+    let entries = get_top_n_calories(elves_parser, 3)?;
+
+    Ok((
+        try_sum_calories(&entries[..1])?,
+        try_sum_calories(&entries)?,
+    ))
+}
+
+fn try_sum_calories(entries: &[LeaderboardEntry]) -> Result<Calories, ElvesParsingError> {
+    let mut calories: Calories = 0;
+
+    for entry in entries {
+        calories = match calories.checked_add(entry.get_calories()) {
+            Some(calories) => calories,
+            None => {
+                return Err(ElvesParsingError::build_overflow_error(
+                    None,
+                    CaloriesOverflowError::build(calories, entry.get_calories()),
+                ))
+            }
+        };
+    }
+
+    Ok(calories)
+}
+
+fn get_top_n_calories(
+    mut elves_parser: ElvesParser,
+    n: usize,
+) -> Result<Vec<LeaderboardEntry>, ElvesParsingError> {
+    #[cfg(not(feature = "parallel"))]
+    let (entries, number_of_elves) = try_get_top_n_calories(&mut elves_parser, n)?;
+
+    #[cfg(feature = "parallel")]
+    let (entries, number_of_elves) = parallel::try_get_top_n_calories(&mut elves_parser, n)?;
+
+    if number_of_elves == 0 {
+        return Err(ElvesParsingError::EmptyInput);
+    }
+
+    Ok(entries)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn try_get_top_n_calories(
+    elves_parser: &mut ElvesParser,
+    n: usize,
+) -> Result<(Vec<LeaderboardEntry>, usize), ElvesParsingError> {
+    let mut leaderboard = Leaderboard::build(n);
+
+    let number_of_elves = elves_parser.try_for_each_elf(|elf_index, elf| {
+        leaderboard.add(LeaderboardEntry::build(elf_index, elf.get_calories()))
+    })?;
+
+    Ok((leaderboard.get_entries(), number_of_elves))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::elf::Elf;
     use crate::parser::{ElvesParser, ElvesParsingError};
+    use std::{error::Error, fs, io};
 
     use super::*;
 
-    fn build_elf(input_calories: Vec<u32>) -> Elf {
+    fn build_elf(input_calories: Vec<Calories>) -> Elf {
         let mut elf = Elf::new();
         for calories in input_calories {
-            elf.add_calories(calories);
+            elf.try_add_snack(Snack::build(calories, 0)).unwrap();
         }
         elf
     }
@@ -62,16 +175,26 @@ mod tests {
         assert_eq!(elves, Ok(expected_elves));
     }
 
+    #[test]
+    fn test_input_file_elves_snack_lines() {
+        let file_name = "../input/test_input.txt";
+
+        let elves = ElvesParser::build(file_name).try_get_elves().unwrap();
+
+        assert_eq!(elves[1].get_snacks(), vec![Snack::build(4000, 5)]);
+        assert_eq!(elves[3].get_largest_snack(), Some(&Snack::build(9000, 12)));
+    }
+
     #[test]
     fn test_non_existing_input_file_elves() {
         let file_name = "../input/wrong_test_input.txt";
 
         let elves = ElvesParser::build(file_name).try_get_elves();
 
-        let expected_error = ElvesParsingError::build(format!(
-            "could not open file {}, No such file or directory (os error 2)",
-            file_name
-        ));
+        let expected_error = ElvesParsingError::build_io_error(
+            &format!("file '{}'", file_name),
+            io::Error::from_raw_os_error(2),
+        );
 
         assert_eq!(elves, Err(expected_error));
     }
@@ -79,12 +202,220 @@ mod tests {
     #[test]
     fn test_input_file_result_1() {
         let test_file_name = "../input/test_input.txt";
-        assert_eq!(get_max_calories_from_one_elf(test_file_name), 24000);
+        assert_eq!(get_max_calories_from_one_elf(test_file_name), Ok(24000));
     }
 
     #[test]
     fn test_input_file_result_2() {
         let test_file_name = "../input/test_input.txt";
-        assert_eq!(get_max_calories_from_three_elves(test_file_name), 45000);
+        assert_eq!(get_max_calories_from_three_elves(test_file_name), Ok(45000));
+    }
+
+    #[test]
+    fn test_input_file_results_in_one_pass() {
+        let test_file_name = "../input/test_input.txt";
+        assert_eq!(
+            get_max_calories_from_one_and_three_elves(test_file_name),
+            Ok((24000, 45000))
+        );
+    }
+
+    #[test]
+    fn test_input_file_inventory_statistics() {
+        let test_file_name = "../input/test_input.txt";
+
+        let statistics = get_inventory_statistics(test_file_name).unwrap();
+
+        assert_eq!(statistics.get_number_of_elves(), 5);
+        assert_eq!(statistics.get_total_calories(), 55000);
+        assert_eq!(statistics.get_max_calories(), 24000);
+        assert_eq!(statistics.get_median_calories(), 10000.0);
+    }
+
+    #[test]
+    fn test_empty_reader_inventory_statistics() {
+        assert_eq!(
+            get_inventory_statistics_from_reader("".as_bytes()),
+            Err(ElvesParsingError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_non_existing_input_file_results() {
+        let test_file_name = "../input/wrong_test_input.txt";
+
+        assert!(matches!(
+            get_max_calories_from_one_elf(test_file_name),
+            Err(ElvesParsingError::Io { .. })
+        ));
+        assert!(matches!(
+            get_max_calories_from_three_elves(test_file_name),
+            Err(ElvesParsingError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_input_file_top_n_calories() {
+        let test_file_name = "../input/test_input.txt";
+
+        let expected_entries = vec![
+            LeaderboardEntry::build(3, 24000),
+            LeaderboardEntry::build(2, 11000),
+            LeaderboardEntry::build(4, 10000),
+            LeaderboardEntry::build(0, 6000),
+        ];
+
+        assert_eq!(top_n_calories(test_file_name, 4), Ok(expected_entries));
+    }
+
+    #[test]
+    fn test_non_existing_input_file_top_n_calories() {
+        let test_file_name = "../input/wrong_test_input.txt";
+
+        assert!(matches!(
+            top_n_calories(test_file_name, 3),
+            Err(ElvesParsingError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reader_elves() {
+        let input = "100\n200\n\n300\n\n400\n500\n";
+
+        let elves = ElvesParser::build_from_reader(input.as_bytes()).try_get_elves();
+
+        let expected_elves = vec![
+            build_elf(vec![100, 200]),
+            build_elf(vec![300]),
+            build_elf(vec![400, 500]),
+        ];
+
+        assert_eq!(elves, Ok(expected_elves));
+    }
+
+    #[test]
+    fn test_reader_elves_parsed_twice() {
+        let input = "100\n200\n\n300\n";
+
+        let mut elves_parser = ElvesParser::build_from_reader(input.as_bytes());
+        elves_parser.try_get_elves().unwrap();
+
+        assert_eq!(
+            elves_parser.try_get_elves(),
+            Err(ElvesParsingError::build_input_already_read_error("reader"))
+        );
+        assert_eq!(
+            elves_parser.try_get_elves().unwrap_err().to_string(),
+            "could not parse elves, reader was already read"
+        );
+    }
+
+    #[test]
+    fn test_invalid_reader_elves() {
+        let input = "100\n  2x0\n";
+
+        let elves = ElvesParser::build_from_reader(input.as_bytes()).try_get_elves();
+
+        let expected_error = ElvesParsingError::build_invalid_number_error(
+            2,
+            4,
+            "2x0",
+            "2x0".parse::<Calories>().unwrap_err(),
+        );
+
+        assert_eq!(elves, Err(expected_error));
+    }
+
+    #[test]
+    fn test_invalid_reader_error_message_and_source() {
+        let input = "100\n\n2x0\n";
+
+        let err = ElvesParser::build_from_reader(input.as_bytes())
+            .try_get_elves()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "could not parse elves, invalid number '2x0' on line 3, column 2, invalid digit found in string"
+        );
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_reader_results() {
+        let input = fs::read_to_string("../input/test_input.txt").unwrap();
+
+        assert_eq!(
+            get_max_calories_from_one_elf_from_reader(input.as_bytes()),
+            Ok(24000)
+        );
+        assert_eq!(
+            get_max_calories_from_three_elves_from_reader(input.as_bytes()),
+            Ok(45000)
+        );
+        assert_eq!(
+            top_n_calories_from_reader(input.as_bytes(), 1),
+            Ok(vec![LeaderboardEntry::build(3, 24000)])
+        );
+        assert_eq!(
+            get_max_calories_from_one_and_three_elves_from_reader(input.as_bytes()),
+            Ok((24000, 45000))
+        );
+    }
+
+    #[test]
+    fn test_empty_reader_results() {
+        let input = "\n\n";
+
+        assert_eq!(
+            get_max_calories_from_one_elf_from_reader(input.as_bytes()),
+            Err(ElvesParsingError::EmptyInput)
+        );
+        assert_eq!(
+            get_max_calories_from_three_elves_from_reader(input.as_bytes()),
+            Err(ElvesParsingError::EmptyInput)
+        );
+        assert_eq!(
+            top_n_calories_from_reader(input.as_bytes(), 3),
+            Err(ElvesParsingError::EmptyInput)
+        );
+        assert_eq!(
+            get_max_calories_from_one_and_three_elves_from_reader(input.as_bytes()),
+            Err(ElvesParsingError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_overflowing_reader_elves() {
+        let input = format!("100\n\n{}\n1\n", Calories::MAX);
+
+        let elves = ElvesParser::build_from_reader(input.as_bytes()).try_get_elves();
+
+        let expected_error = ElvesParsingError::build_overflow_error(
+            Some(4),
+            CaloriesOverflowError::build(Calories::MAX, 1),
+        );
+
+        assert_eq!(elves, Err(expected_error));
+    }
+
+    #[test]
+    fn test_overflowing_reader_results() {
+        let input = format!("{}\n\n{}\n", Calories::MAX, Calories::MAX);
+
+        assert_eq!(
+            get_max_calories_from_one_elf_from_reader(input.as_bytes()),
+            Ok(Calories::MAX)
+        );
+        assert_eq!(
+            get_max_calories_from_three_elves_from_reader(input.as_bytes()),
+            Err(ElvesParsingError::build_overflow_error(
+                None,
+                CaloriesOverflowError::build(Calories::MAX, Calories::MAX)
+            ))
+        );
     }
 }
@@ -1,17 +1,297 @@
 use advent_of_code_2022_day_01::{
-    get_max_calories_from_one_elf, get_max_calories_from_three_elves,
+    get_inventory_statistics, get_inventory_statistics_from_reader,
+    get_max_calories_from_one_and_three_elves,
+    get_max_calories_from_one_and_three_elves_from_reader, CalorieTracker, GeneratedAnswers,
+    InventoryGenerator, LeaderboardEntry,
 };
+use std::{
+    env, fs,
+    io::{self, BufRead},
+    mem,
+    str::FromStr,
+    thread, time,
+};
+use tracing::Level;
+
+const DEFAULT_NUMBER_OF_BINS: usize = 10;
+const FOLLOW_INTERVAL: time::Duration = time::Duration::from_millis(500);
+const USAGE: &str = "\
+Usage: advent_of_code_2022_day_01 [-v|-vv|-vvv] [COMMAND] [FILE|-]
+
+Commands:
+  stats [--json] [--bins N]    print statistics of the inventory
+  generate [--seed N] [--elves N] [--snacks MIN-MAX] [--calories MIN-MAX]
+                               write a generated inventory to the file or stdout
+  follow                       print the max calories whenever the file grows
+
+Without a command the max calories of one and three elves are printed, '-' reads stdin";
+
+enum Command {
+    MaxCalories,
+    Stats {
+        as_json: bool,
+        number_of_bins: usize,
+    },
+    Generate {
+        generator: InventoryGenerator,
+    },
+    Follow,
+}
 
 fn main() {
-    let input_file_name = "../input/input.txt";
+    let mut input_file_name = None;
+    let mut verbosity = 0;
+    let mut command = Command::MaxCalories;
 
-    println!(
-        "Most calories carried by an elf: {:?}",
-        get_max_calories_from_one_elf(input_file_name)
-    );
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), &mut command) {
+            ("-v" | "--verbose", _) => verbosity += 1,
+            ("-vv", _) => verbosity += 2,
+            ("-vvv", _) => verbosity += 3,
+            ("stats", _) => {
+                command = Command::Stats {
+                    as_json: false,
+                    number_of_bins: DEFAULT_NUMBER_OF_BINS,
+                }
+            }
+            ("--json", Command::Stats { as_json, .. }) => *as_json = true,
+            ("--bins", Command::Stats { number_of_bins, .. }) => {
+                match parse_next_arg(&mut args, "--bins") {
+                    Some(bins) => *number_of_bins = bins,
+                    None => return,
+                }
+            }
+            ("generate", _) => {
+                command = Command::Generate {
+                    generator: InventoryGenerator::default(),
+                }
+            }
+            ("follow", _) => command = Command::Follow,
+            ("--seed", Command::Generate { generator }) => {
+                match parse_next_arg(&mut args, "--seed") {
+                    Some(seed) => *generator = mem::take(generator).with_seed(seed),
+                    None => return,
+                }
+            }
+            ("--elves", Command::Generate { generator }) => {
+                match parse_next_arg(&mut args, "--elves") {
+                    Some(elves) => *generator = mem::take(generator).with_number_of_elves(elves),
+                    None => return,
+                }
+            }
+            ("--snacks", Command::Generate { generator }) => {
+                match parse_next_range(&mut args, "--snacks") {
+                    Some((min, max)) => {
+                        *generator = mem::take(generator).with_snacks_per_elf(min, max)
+                    }
+                    None => return,
+                }
+            }
+            ("--calories", Command::Generate { generator }) => {
+                match parse_next_range(&mut args, "--calories") {
+                    Some((min, max)) => {
+                        *generator = mem::take(generator).with_calories_per_snack(min, max)
+                    }
+                    None => return,
+                }
+            }
+            // a lone '-' stands for stdin, any other dash is an unknown or misplaced flag
+            _ if arg.starts_with('-') && arg != "-" => {
+                eprintln!("Unexpected argument '{}'", arg);
+                eprintln!("{}", USAGE);
+                return;
+            }
+            _ => input_file_name = Some(arg),
+        }
+    }
+
+    init_tracing(verbosity);
+
+    match command {
+        Command::MaxCalories => print_max_calories(&get_input_file_name(input_file_name)),
+        Command::Stats {
+            as_json,
+            number_of_bins,
+        } => print_inventory_statistics(
+            &get_input_file_name(input_file_name),
+            as_json,
+            number_of_bins,
+        ),
+        Command::Generate { generator } => {
+            generate_inventory(&generator, input_file_name.as_deref())
+        }
+        Command::Follow => follow_max_calories(&get_input_file_name(input_file_name)),
+    }
+}
+
+fn get_input_file_name(input_file_name: Option<String>) -> String {
+    input_file_name.unwrap_or_else(|| String::from("../input/input.txt"))
+}
+
+fn parse_next_arg<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> Option<T> {
+    match args.next().map(|arg| arg.parse::<T>()) {
+        Some(Ok(value)) => Some(value),
+        _ => {
+            eprintln!("Expected a number after '{}'", flag);
+            None
+        }
+    }
+}
+
+fn parse_next_range<T: FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    flag: &str,
+) -> Option<(T, T)> {
+    let range = args.next().and_then(|arg| {
+        let (min, max) = arg.split_once('-')?;
+        Some((min.parse::<T>().ok()?, max.parse::<T>().ok()?))
+    });
+
+    if range.is_none() {
+        eprintln!("Expected a range 'MIN-MAX' after '{}'", flag);
+    }
+
+    range
+}
+
+fn init_tracing(verbosity: usize) {
+    let level = match verbosity {
+        0 => return,
+        1 => Level::INFO,
+        2 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .init();
+}
+
+fn print_max_calories(input_file_name: &str) {
+    let max_calories = if input_file_name == "-" {
+        get_max_calories_from_one_and_three_elves_from_reader(io::stdin().lock())
+    } else {
+        get_max_calories_from_one_and_three_elves(input_file_name)
+    };
 
-    println!(
-        "Most calories carried by three elves: {:?}",
-        get_max_calories_from_three_elves(input_file_name)
-    )
+    match max_calories {
+        Ok((max_calories_from_one_elf, max_calories_from_three_elves)) => {
+            println!(
+                "Most calories carried by an elf: {}",
+                max_calories_from_one_elf
+            );
+
+            println!(
+                "Most calories carried by three elves: {}",
+                max_calories_from_three_elves
+            )
+        }
+        Err(err) => eprintln!(
+            "Error while getting most calories carried by elves, {}",
+            err
+        ),
+    }
+}
+
+fn follow_max_calories(input_file_name: &str) {
+    if input_file_name == "-" {
+        track_max_calories(io::stdin().lock(), false);
+        return;
+    }
+
+    match fs::File::open(input_file_name) {
+        Ok(file) => track_max_calories(io::BufReader::new(file), true),
+        Err(err) => eprintln!("Error while following file '{}', {}", input_file_name, err),
+    }
+}
+
+fn track_max_calories<R: BufRead>(mut reader: R, wait_for_lines: bool) {
+    let mut tracker = CalorieTracker::build();
+    let mut top_three: Vec<LeaderboardEntry> = Vec::new();
+    let mut line = String::new();
+
+    loop {
+        match reader.read_line(&mut line) {
+            Ok(0) if wait_for_lines => {
+                thread::sleep(FOLLOW_INTERVAL);
+                continue;
+            }
+            Ok(0) => return,
+            Ok(_) if wait_for_lines && !line.ends_with('\n') => continue,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("Error while reading lines, {}", err);
+                return;
+            }
+        }
+
+        if let Err(err) = tracker.try_push_line(&line) {
+            eprintln!("Skipped line, {}", err);
+        }
+        line.clear();
+
+        let current_top_three = tracker.get_top_three();
+        if current_top_three != top_three {
+            print_tracked_max_calories(&tracker);
+            top_three = current_top_three;
+        }
+    }
+}
+
+fn print_tracked_max_calories(tracker: &CalorieTracker) {
+    match (
+        tracker.try_get_max_calories_from_one_elf(),
+        tracker.try_get_max_calories_from_three_elves(),
+    ) {
+        (Ok(max_calories_from_one_elf), Ok(max_calories_from_three_elves)) => println!(
+            "Elves: {}, most calories carried by an elf: {}, by three elves: {}",
+            tracker.get_number_of_elves(),
+            max_calories_from_one_elf,
+            max_calories_from_three_elves
+        ),
+        (Err(err), _) | (_, Err(err)) => eprintln!(
+            "Error while getting most calories carried by elves, {}",
+            err
+        ),
+    }
+}
+
+fn print_inventory_statistics(input_file_name: &str, as_json: bool, number_of_bins: usize) {
+    let statistics = if input_file_name == "-" {
+        get_inventory_statistics_from_reader(io::stdin().lock())
+    } else {
+        get_inventory_statistics(input_file_name)
+    };
+
+    match statistics {
+        Ok(statistics) if as_json => println!("{}", statistics.format_json(number_of_bins)),
+        Ok(statistics) => print!("{}", statistics.format_table(number_of_bins)),
+        Err(err) => eprintln!("Error while getting inventory statistics, {}", err),
+    }
+}
+
+fn generate_inventory(generator: &InventoryGenerator, output_file_name: Option<&str>) {
+    let answers = match output_file_name {
+        None | Some("-") => generator.try_write(io::stdout().lock()),
+        Some(output_file_name) => generator.try_write_file(output_file_name),
+    };
+
+    match answers {
+        Ok(answers) => print_generated_answers(&answers),
+        Err(err) => eprintln!("Error while generating inventory, {}", err),
+    }
+}
+
+fn print_generated_answers(answers: &GeneratedAnswers) {
+    eprintln!("Generated elves: {}", answers.get_number_of_elves());
+    eprintln!(
+        "Most calories carried by an elf: {}",
+        answers.get_max_calories_from_one_elf()
+    );
+    eprintln!(
+        "Most calories carried by three elves: {}",
+        answers.get_max_calories_from_three_elves()
+    );
 }
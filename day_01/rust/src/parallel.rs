@@ -0,0 +1,144 @@
+use crate::{
+    leaderboard::{Leaderboard, LeaderboardEntry},
+    parser::{ElvesChunk, ElvesParser, ElvesParsingError},
+};
+use rayon::prelude::*;
+
+const CHUNK_SIZE: usize = 1 << 16;
+
+pub fn try_get_top_n_calories(
+    elves_parser: &mut ElvesParser,
+    n: usize,
+) -> Result<(Vec<LeaderboardEntry>, usize), ElvesParsingError> {
+    try_get_top_n_calories_in_chunks(elves_parser, n, CHUNK_SIZE)
+}
+
+fn try_get_top_n_calories_in_chunks(
+    elves_parser: &mut ElvesParser,
+    n: usize,
+    chunk_size: usize,
+) -> Result<(Vec<LeaderboardEntry>, usize), ElvesParsingError> {
+    let mut chunks = elves_parser.try_get_chunks(chunk_size)?;
+    let batch_size = rayon::current_num_threads() * 4;
+
+    let mut leaderboard = Leaderboard::build(n);
+    let mut number_of_elves = 0;
+
+    loop {
+        let batch = chunks
+            .by_ref()
+            .take(batch_size)
+            .collect::<Result<Vec<ElvesChunk>, ElvesParsingError>>()?;
+
+        if batch.is_empty() {
+            break;
+        }
+
+        let chunk_results: Vec<Result<(Vec<LeaderboardEntry>, usize), ElvesParsingError>> = batch
+            .par_iter()
+            .map(|chunk| try_get_chunk_top_n_calories(chunk, n))
+            .collect();
+
+        for chunk_result in chunk_results {
+            let (entries, number_of_chunk_elves) = chunk_result?;
+
+            for entry in entries {
+                leaderboard.add(LeaderboardEntry::build(
+                    number_of_elves + entry.get_elf_index(),
+                    entry.get_calories(),
+                ));
+            }
+
+            number_of_elves += number_of_chunk_elves;
+        }
+    }
+
+    Ok((leaderboard.get_entries(), number_of_elves))
+}
+
+fn try_get_chunk_top_n_calories(
+    chunk: &ElvesChunk,
+    n: usize,
+) -> Result<(Vec<LeaderboardEntry>, usize), ElvesParsingError> {
+    let mut leaderboard = Leaderboard::build(n);
+
+    let number_of_elves = chunk.try_for_each_elf(|elf_index, elf| {
+        leaderboard.add(LeaderboardEntry::build(elf_index, elf.get_calories()))
+    })?;
+
+    Ok((leaderboard.get_entries(), number_of_elves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ElfGrouping;
+
+    const INPUTS: [&str; 5] = [
+        "",
+        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n",
+        "\n\n100\n0\n\n\n0\n\n200\n\n\n\n300\n100\n\n",
+        "0\n\n0\n\n500\n\n0\n500\n\n500\n",
+        "5\n\n4\n\n3\n\n2\n\n1\n\n2\n\n3\n\n4\n\n5\n\n",
+    ];
+
+    const GROUPINGS: [ElfGrouping; 3] = [
+        ElfGrouping::MergeZeroCalories,
+        ElfGrouping::NonEmptyGroups,
+        ElfGrouping::AllGroups,
+    ];
+
+    fn get_top_n_calories_sequentially(
+        input: &str,
+        grouping: ElfGrouping,
+        n: usize,
+    ) -> (Vec<LeaderboardEntry>, usize) {
+        let elves = ElvesParser::build_from_reader(input.as_bytes())
+            .with_grouping(grouping)
+            .try_get_elves()
+            .unwrap();
+
+        let mut leaderboard = Leaderboard::build(n);
+        for (elf_index, elf) in elves.iter().enumerate() {
+            leaderboard.add(LeaderboardEntry::build(elf_index, elf.get_calories()));
+        }
+
+        (leaderboard.get_entries(), elves.len())
+    }
+
+    #[test]
+    fn test_chunked_top_n_calories_matches_sequential() {
+        for input in INPUTS {
+            for grouping in GROUPINGS {
+                for chunk_size in [0, 1, 2, 3, 5, 100] {
+                    for n in [1, 3, 10] {
+                        let mut elves_parser = ElvesParser::build_from_reader(input.as_bytes())
+                            .with_grouping(grouping);
+
+                        assert_eq!(
+                            try_get_top_n_calories_in_chunks(&mut elves_parser, n, chunk_size),
+                            Ok(get_top_n_calories_sequentially(input, grouping, n)),
+                            "input {:?}, grouping {:?}, chunk size {}, top {}",
+                            input,
+                            grouping,
+                            chunk_size,
+                            n
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunked_top_n_calories_invalid_line() {
+        let input = "100\n\n200\n\n300\n\n4a0\n\n500\n";
+
+        let mut elves_parser = ElvesParser::build_from_reader(input.as_bytes());
+
+        assert!(matches!(
+            try_get_top_n_calories_in_chunks(&mut elves_parser, 3, 1),
+            Err(ElvesParsingError::InvalidNumber { line: 7, .. })
+        ));
+    }
+}
@@ -1,124 +1,402 @@
-use crate::elf::Elf;
+#[cfg(feature = "parallel")]
+mod chunks;
+mod error;
+
+#[cfg(feature = "parallel")]
+pub use chunks::{ElvesChunk, ElvesChunks};
+pub use error::ElvesParsingError;
+
+use crate::elf::{Calories, Elf, Snack};
 use std::{
-    error, fmt, fs,
+    fs,
     io::{self, BufRead},
-    path,
+    mem, path,
 };
+use tracing::{debug, error, info, info_span, trace};
 
-#[derive(Debug, PartialEq)]
-pub struct ElvesParsingError {
-    cause: String,
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ElfGrouping {
+    #[default]
+    MergeZeroCalories,
+    NonEmptyGroups,
+    AllGroups,
 }
 
-impl ElvesParsingError {
-    pub fn build(cause: String) -> Self {
-        ElvesParsingError { cause }
-    }
+enum ElvesInput<'a> {
+    File {
+        file_name: String,
+    },
+    Reader {
+        reader: Option<Box<dyn BufRead + 'a>>,
+    },
 }
 
-impl error::Error for ElvesParsingError {}
-
-impl fmt::Display for ElvesParsingError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "could not parse elves, {}", self.cause)
-    }
-}
-
-pub struct ElvesParser {
-    file_name: String,
+pub struct ElvesParser<'a> {
+    input: ElvesInput<'a>,
+    input_name: String,
+    grouping: ElfGrouping,
+    line_index_offset: usize,
     current_line: String,
     current_line_index: usize,
-    elves: Vec<Elf>,
+    current_line_offset: usize,
+    current_elf: Elf,
+    current_elf_index: usize,
 }
 
-impl ElvesParser {
+impl<'a> ElvesParser<'a> {
     pub fn build(file_name: &str) -> Self {
+        Self::build_from_input(
+            ElvesInput::File {
+                file_name: String::from(file_name),
+            },
+            format!("file '{}'", file_name),
+        )
+    }
+
+    pub fn build_from_reader<R>(reader: R) -> Self
+    where
+        R: BufRead + 'a,
+    {
+        Self::build_from_input(
+            ElvesInput::Reader {
+                reader: Some(Box::new(reader)),
+            },
+            String::from("reader"),
+        )
+    }
+
+    fn build_from_input(input: ElvesInput<'a>, input_name: String) -> Self {
         ElvesParser {
-            file_name: String::from(file_name),
+            input,
+            input_name,
+            grouping: ElfGrouping::default(),
+            line_index_offset: 0,
             current_line: String::new(),
-            current_line_index: 1,
-            elves: Vec::new(),
+            current_line_index: 0,
+            current_line_offset: 0,
+            current_elf: Elf::new(),
+            current_elf_index: 0,
         }
     }
 
+    pub fn with_grouping(mut self, grouping: ElfGrouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
     pub fn try_get_elves(&mut self) -> Result<Vec<Elf>, ElvesParsingError> {
-        let file = self.try_open_file()?;
+        let mut elves = Vec::new();
+
+        self.try_for_each_elf(|_, elf| elves.push(elf))?;
 
-        for (index, line) in file.lines().enumerate() {
-            self.try_set_current_line_and_index(line, index)?;
+        Ok(elves)
+    }
 
-            self.add_new_elf_if_current_line_is_first_line();
+    pub fn try_for_each_elf<F>(&mut self, mut on_elf: F) -> Result<usize, ElvesParsingError>
+    where
+        F: FnMut(usize, Elf),
+    {
+        let span = info_span!(
+            "parse_elves",
+            input = %self.input_name,
+            grouping = ?self.grouping,
+            first_line = self.line_index_offset + 1
+        );
+        let _entered = span.enter();
 
-            if self.current_line.is_empty() {
-                self.add_new_elf_if_current_elf_has_calories();
-                continue;
-            }
+        let result = self.try_parse_input(&mut on_elf);
 
-            self.try_add_calories_to_current_elf()?
+        match &result {
+            Ok(number_of_elves) => info!(number_of_elves, "parsed elves"),
+            Err(err) => error!(line = self.current_line_index, %err, "could not parse elves"),
         }
 
-        Ok(self.elves.clone())
+        result
     }
 
-    fn try_open_file(&self) -> Result<io::BufReader<fs::File>, ElvesParsingError> {
-        match fs::File::open(path::Path::new(&self.file_name)) {
-            Ok(file) => Ok(io::BufReader::new(file)),
-            Err(err) => Err(ElvesParsingError::build(format!(
-                "could not open file {}, {}",
-                self.file_name, err
-            ))),
+    fn try_parse_input<F>(&mut self, on_elf: &mut F) -> Result<usize, ElvesParsingError>
+    where
+        F: FnMut(usize, Elf),
+    {
+        let input = self.try_open_input()?;
+
+        self.reset();
+
+        for line in input.lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Err(ElvesParsingError::build_io_error(&self.input_name, err)),
+            };
+
+            self.try_parse_line(&line, on_elf)?;
         }
+
+        self.finish_input(on_elf);
+
+        Ok(self.current_elf_index)
     }
 
-    fn try_set_current_line_and_index(
+    pub(crate) fn try_parse_line<F>(
         &mut self,
-        line: Result<String, io::Error>,
-        index: usize,
-    ) -> Result<(), ElvesParsingError> {
-        self.current_line = match line {
-            Ok(line) => line.trim().to_string(),
-            Err(err) => {
-                return Err(ElvesParsingError::build(format!(
-                    "error in file '{}' on line {}, {}",
-                    self.file_name, self.current_line_index, err
-                )))
+        line: &str,
+        on_elf: &mut F,
+    ) -> Result<(), ElvesParsingError>
+    where
+        F: FnMut(usize, Elf),
+    {
+        self.set_current_line(line);
+
+        if self.current_line.is_empty() {
+            if !self.finish_current_elf_if_it_is_complete(on_elf) {
+                trace!(line = self.current_line_index, "skipped blank line");
             }
-        };
+            return Ok(());
+        }
 
-        self.current_line_index = index + 1;
+        self.try_add_calories_to_current_elf()
+    }
 
-        Ok(())
+    fn finish_input<F>(&mut self, on_elf: &mut F)
+    where
+        F: FnMut(usize, Elf),
+    {
+        if self.has_current_elf_line() {
+            self.finish_current_elf_if_it_is_complete(on_elf);
+        }
     }
 
-    fn add_new_elf_if_current_line_is_first_line(&mut self) {
-        if self.current_line_index == 1 {
-            self.elves.push(Elf::new());
+    pub(crate) fn get_number_of_finished_elves(&self) -> usize {
+        self.current_elf_index
+    }
+
+    pub(crate) fn get_current_elf_if_it_is_complete(&self) -> Option<(usize, &Elf)> {
+        if self.has_current_elf_line() && self.is_current_elf_complete() {
+            Some((self.current_elf_index, &self.current_elf))
+        } else {
+            None
         }
     }
 
-    fn add_new_elf_if_current_elf_has_calories(&mut self) {
-        if let Some(elf) = self.elves.last() {
-            if elf.has_calories() {
-                self.elves.push(Elf::new());
-            }
+    fn reset(&mut self) {
+        self.current_line = String::new();
+        self.current_line_index = self.line_index_offset;
+        self.current_line_offset = 0;
+        self.current_elf = Elf::new();
+        self.current_elf_index = 0;
+    }
+
+    fn try_open_input(&mut self) -> Result<Box<dyn BufRead + 'a>, ElvesParsingError> {
+        match &mut self.input {
+            ElvesInput::File { file_name } => Self::try_open_file(file_name, &self.input_name),
+            ElvesInput::Reader { reader } => match reader.take() {
+                Some(reader) => Ok(reader),
+                None => Err(ElvesParsingError::build_input_already_read_error(
+                    &self.input_name,
+                )),
+            },
+        }
+    }
+
+    fn try_open_file(
+        file_name: &str,
+        input_name: &str,
+    ) -> Result<Box<dyn BufRead + 'a>, ElvesParsingError> {
+        match fs::File::open(path::Path::new(file_name)) {
+            Ok(file) => Ok(Box::new(io::BufReader::new(file))),
+            Err(err) => Err(ElvesParsingError::build_io_error(input_name, err)),
+        }
+    }
+
+    fn set_current_line(&mut self, line: &str) {
+        self.current_line = line.trim().to_string();
+        self.current_line_offset = line.chars().take_while(|c| c.is_whitespace()).count();
+
+        self.current_line_index += 1;
+    }
+
+    fn has_current_line(&self) -> bool {
+        self.current_line_index > self.line_index_offset
+    }
+
+    // a blank last line only separates the elves before it, it does not start a new one
+    fn has_current_elf_line(&self) -> bool {
+        self.has_current_line() && !self.current_line.is_empty()
+    }
+
+    fn finish_current_elf_if_it_is_complete<F>(&mut self, on_elf: &mut F) -> bool
+    where
+        F: FnMut(usize, Elf),
+    {
+        if !self.is_current_elf_complete() {
+            return false;
+        }
+
+        let elf = mem::replace(&mut self.current_elf, Elf::new());
+
+        debug!(
+            elf_index = self.current_elf_index,
+            calories = %elf.get_calories(),
+            number_of_snacks = elf.get_number_of_snacks(),
+            line = self.current_line_index,
+            "finished elf"
+        );
+
+        on_elf(self.current_elf_index, elf);
+        self.current_elf_index += 1;
+
+        true
+    }
+
+    fn is_current_elf_complete(&self) -> bool {
+        match self.grouping {
+            ElfGrouping::MergeZeroCalories => self.current_elf.has_calories(),
+            ElfGrouping::NonEmptyGroups => self.current_elf.has_snacks(),
+            ElfGrouping::AllGroups => true,
         }
     }
 
     fn try_add_calories_to_current_elf(&mut self) -> Result<(), ElvesParsingError> {
-        let calories = match self.current_line.parse::<u32>() {
+        let calories = match self.current_line.parse::<Calories>() {
             Ok(calories) => calories,
             Err(err) => {
-                return Err(ElvesParsingError::build(format!(
-                    "error in file '{}' on line {}, {}",
-                    self.file_name, self.current_line_index, err
-                )))
+                return Err(ElvesParsingError::build_invalid_number_error(
+                    self.current_line_index,
+                    self.get_current_invalid_column(),
+                    &self.current_line,
+                    err,
+                ))
             }
         };
 
-        if let Some(elf) = self.elves.last_mut() {
-            elf.add_calories(calories);
+        let snack = Snack::build(calories, self.current_line_index);
+
+        match self.current_elf.try_add_snack(snack) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(ElvesParsingError::build_overflow_error(
+                Some(self.current_line_index),
+                err,
+            )),
         }
+    }
+
+    fn get_current_invalid_column(&self) -> usize {
+        let invalid_position = self
+            .current_line
+            .chars()
+            .position(|c| !c.is_ascii_digit())
+            .unwrap_or_default();
+
+        self.current_line_offset + invalid_position + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_calories_list(input: &str, grouping: ElfGrouping) -> Vec<Calories> {
+        ElvesParser::build_from_reader(input.as_bytes())
+            .with_grouping(grouping)
+            .try_get_elves()
+            .unwrap()
+            .iter()
+            .map(|elf| elf.get_calories())
+            .collect()
+    }
+
+    #[test]
+    fn test_zero_calories_elf_merge_zero_calories() {
+        let input = "0\n\n100\n\n200\n";
+        let calories_list = get_calories_list(input, ElfGrouping::MergeZeroCalories);
+        assert_eq!(calories_list, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_zero_calories_elf_default_grouping() {
+        let input = "0\n\n100\n\n200\n";
+
+        let calories_list: Vec<Calories> = ElvesParser::build_from_reader(input.as_bytes())
+            .try_get_elves()
+            .unwrap()
+            .iter()
+            .map(|elf| elf.get_calories())
+            .collect();
+
+        assert_eq!(ElfGrouping::default(), ElfGrouping::MergeZeroCalories);
+        assert_eq!(calories_list, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_zero_calories_elf_non_empty_groups() {
+        let input = "0\n\n100\n\n200\n";
+        let calories_list = get_calories_list(input, ElfGrouping::NonEmptyGroups);
+        assert_eq!(calories_list, vec![0, 100, 200]);
+    }
+
+    #[test]
+    fn test_zero_calories_elf_all_groups() {
+        let input = "0\n\n100\n\n200\n";
+        let calories_list = get_calories_list(input, ElfGrouping::AllGroups);
+        assert_eq!(calories_list, vec![0, 100, 200]);
+    }
+
+    #[test]
+    fn test_consecutive_blank_lines_merge_zero_calories() {
+        let input = "\n100\n\n\n\n200\n\n";
+        let calories_list = get_calories_list(input, ElfGrouping::MergeZeroCalories);
+        assert_eq!(calories_list, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_consecutive_blank_lines_non_empty_groups() {
+        let input = "\n100\n\n\n\n200\n\n";
+        let calories_list = get_calories_list(input, ElfGrouping::NonEmptyGroups);
+        assert_eq!(calories_list, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_consecutive_blank_lines_all_groups() {
+        let input = "\n100\n\n\n\n200\n\n";
+        let calories_list = get_calories_list(input, ElfGrouping::AllGroups);
+        assert_eq!(calories_list, vec![0, 100, 0, 0, 200]);
+    }
+
+    #[test]
+    fn test_empty_input_all_groups() {
+        let calories_list = get_calories_list("", ElfGrouping::AllGroups);
+        assert_eq!(calories_list, vec![]);
+    }
+
+    #[test]
+    fn test_empty_groups_have_no_snacks() {
+        let elves = ElvesParser::build_from_reader("100\n\n\n0\n".as_bytes())
+            .with_grouping(ElfGrouping::AllGroups)
+            .try_get_elves()
+            .unwrap();
+
+        let numbers_of_snacks: Vec<usize> =
+            elves.iter().map(|elf| elf.get_number_of_snacks()).collect();
+
+        assert_eq!(numbers_of_snacks, vec![1, 0, 1]);
+    }
+
+    #[test]
+    fn test_trailing_blank_lines_all_groups() {
+        let calories_list = get_calories_list(
+            "100
+
+",
+            ElfGrouping::AllGroups,
+        );
+        assert_eq!(calories_list, vec![100]);
+
+        let calories_list = get_calories_list(
+            "100
+
 
-        Ok(())
+",
+            ElfGrouping::AllGroups,
+        );
+        assert_eq!(calories_list, vec![100, 0]);
     }
 }
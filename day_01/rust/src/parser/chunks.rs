@@ -0,0 +1,241 @@
+use super::{ElfGrouping, ElvesParser, ElvesParsingError};
+use crate::elf::Elf;
+use std::io::{self, BufRead};
+
+impl<'a> ElvesParser<'a> {
+    pub fn try_get_chunks(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<ElvesChunks<'a>, ElvesParsingError> {
+        let input = self.try_open_input()?;
+
+        Ok(ElvesChunks {
+            lines: input.lines(),
+            input_name: self.input_name.clone(),
+            grouping: self.grouping,
+            chunk_size,
+            line_index: 0,
+            next_line: None,
+            is_finished: false,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ElvesChunk {
+    input_name: String,
+    grouping: ElfGrouping,
+    line_index_offset: usize,
+    number_of_lines: usize,
+    content: String,
+}
+
+impl ElvesChunk {
+    fn build(input_name: &str, grouping: ElfGrouping, line_index_offset: usize) -> Self {
+        ElvesChunk {
+            input_name: String::from(input_name),
+            grouping,
+            line_index_offset,
+            number_of_lines: 0,
+            content: String::new(),
+        }
+    }
+
+    fn push_line(&mut self, line: &str) {
+        self.content.push_str(line);
+        self.content.push('\n');
+        self.number_of_lines += 1;
+    }
+
+    pub fn try_for_each_elf<F>(&self, on_elf: F) -> Result<usize, ElvesParsingError>
+    where
+        F: FnMut(usize, Elf),
+    {
+        let mut elves_parser =
+            ElvesParser::build_from_reader(self.content.as_bytes()).with_grouping(self.grouping);
+        elves_parser.input_name = self.input_name.clone();
+        elves_parser.line_index_offset = self.line_index_offset;
+
+        elves_parser.try_for_each_elf(on_elf)
+    }
+}
+
+pub struct ElvesChunks<'a> {
+    lines: io::Lines<Box<dyn BufRead + 'a>>,
+    input_name: String,
+    grouping: ElfGrouping,
+    chunk_size: usize,
+    line_index: usize,
+    next_line: Option<String>,
+    is_finished: bool,
+}
+
+impl<'a> ElvesChunks<'a> {
+    fn try_read_line(&mut self) -> Result<Option<String>, ElvesParsingError> {
+        if let Some(line) = self.next_line.take() {
+            return Ok(Some(line));
+        }
+
+        match self.lines.next() {
+            Some(Ok(line)) => {
+                self.line_index += 1;
+                Ok(Some(line))
+            }
+            Some(Err(err)) => {
+                self.is_finished = true;
+                Err(ElvesParsingError::build_io_error(&self.input_name, err))
+            }
+            None => {
+                self.is_finished = true;
+                Ok(None)
+            }
+        }
+    }
+
+    fn get_next_line_index_offset(&self) -> usize {
+        match self.next_line {
+            Some(_) => self.line_index - 1,
+            None => self.line_index,
+        }
+    }
+}
+
+// chunks are only split on a blank line, which is kept as the last line of the chunk before it
+// so that it still finishes that chunk's last elf and every chunk can be parsed on its own
+impl<'a> Iterator for ElvesChunks<'a> {
+    type Item = Result<ElvesChunk, ElvesParsingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished && self.next_line.is_none() {
+            return None;
+        }
+
+        let mut chunk = ElvesChunk::build(
+            &self.input_name,
+            self.grouping,
+            self.get_next_line_index_offset(),
+        );
+
+        loop {
+            let line = match self.try_read_line() {
+                Ok(Some(line)) => line,
+                Ok(None) => break,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if chunk.number_of_lines >= self.chunk_size.max(1) && line.trim().is_empty() {
+                match self.try_read_line() {
+                    Ok(Some(next_line)) => {
+                        chunk.push_line(&line);
+                        self.next_line = Some(next_line);
+                        return Some(Ok(chunk));
+                    }
+                    Ok(None) => {
+                        chunk.push_line(&line);
+                        break;
+                    }
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+
+            chunk.push_line(&line);
+        }
+
+        if chunk.number_of_lines == 0 {
+            None
+        } else {
+            Some(Ok(chunk))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf::Calories;
+
+    fn get_chunks(input: &str, chunk_size: usize) -> Vec<ElvesChunk> {
+        ElvesParser::build_from_reader(input.as_bytes())
+            .try_get_chunks(chunk_size)
+            .unwrap()
+            .collect::<Result<Vec<ElvesChunk>, ElvesParsingError>>()
+            .unwrap()
+    }
+
+    fn build_chunk(line_index_offset: usize, lines: Vec<&str>) -> ElvesChunk {
+        let mut chunk = ElvesChunk::build("reader", ElfGrouping::default(), line_index_offset);
+        for line in lines {
+            chunk.push_line(line);
+        }
+        chunk
+    }
+
+    #[test]
+    fn test_empty_input_chunks() {
+        assert_eq!(get_chunks("", 1), vec![]);
+    }
+
+    #[test]
+    fn test_chunks_are_split_on_blank_lines() {
+        let input = "100\n200\n\n300\n\n400\n";
+
+        let expected_chunks = vec![
+            build_chunk(0, vec!["100", "200", ""]),
+            build_chunk(3, vec!["300", ""]),
+            build_chunk(5, vec!["400"]),
+        ];
+
+        assert_eq!(get_chunks(input, 1), expected_chunks);
+    }
+
+    #[test]
+    fn test_chunks_are_not_split_before_chunk_size() {
+        let input = "100\n\n200\n\n300\n\n400\n";
+
+        let expected_chunks = vec![
+            build_chunk(0, vec!["100", "", "200", ""]),
+            build_chunk(4, vec!["300", "", "400"]),
+        ];
+
+        assert_eq!(get_chunks(input, 3), expected_chunks);
+    }
+
+    #[test]
+    fn test_chunks_keep_consecutive_blank_lines() {
+        let input = "100\n\n\n\n200\n";
+
+        let expected_chunks = vec![
+            build_chunk(0, vec!["100", ""]),
+            build_chunk(2, vec!["", ""]),
+            build_chunk(4, vec!["200"]),
+        ];
+
+        assert_eq!(get_chunks(input, 1), expected_chunks);
+    }
+
+    #[test]
+    fn test_chunks_keep_trailing_blank_line() {
+        let input = "100\n\n";
+
+        let expected_chunks = vec![build_chunk(0, vec!["100", ""])];
+
+        assert_eq!(get_chunks(input, 1), expected_chunks);
+    }
+
+    #[test]
+    fn test_chunk_line_indices() {
+        let input = "100\n\n200\n\n3x0\n";
+
+        let chunks = get_chunks(input, 1);
+
+        assert_eq!(
+            chunks[2].try_for_each_elf(|_, _| {}),
+            Err(ElvesParsingError::build_invalid_number_error(
+                5,
+                2,
+                "3x0",
+                "3x0".parse::<Calories>().unwrap_err()
+            ))
+        );
+    }
+}
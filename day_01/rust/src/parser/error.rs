@@ -0,0 +1,160 @@
+use crate::elf::CaloriesOverflowError;
+use std::{error, fmt, io, num};
+
+#[derive(Debug)]
+pub enum ElvesParsingError {
+    Io {
+        input_name: String,
+        source: io::Error,
+    },
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        text: String,
+        source: num::ParseIntError,
+    },
+    Overflow {
+        line: Option<usize>,
+        source: CaloriesOverflowError,
+    },
+    EmptyInput,
+    InputAlreadyRead {
+        input_name: String,
+    },
+}
+
+impl fmt::Display for ElvesParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ElvesParsingError::Io { input_name, source } => {
+                write!(
+                    f,
+                    "could not parse elves, could not read {}, {}",
+                    input_name, source
+                )
+            }
+            ElvesParsingError::InvalidNumber {
+                line,
+                column,
+                text,
+                source,
+            } => write!(
+                f,
+                "could not parse elves, invalid number '{}' on line {}, column {}, {}",
+                text, line, column, source
+            ),
+            ElvesParsingError::Overflow {
+                line: Some(line),
+                source,
+            } => write!(
+                f,
+                "could not parse elves, overflow on line {}, {}",
+                line, source
+            ),
+            ElvesParsingError::Overflow { line: None, source } => {
+                write!(f, "could not parse elves, overflow, {}", source)
+            }
+            ElvesParsingError::EmptyInput => write!(f, "could not parse elves, input is empty"),
+            ElvesParsingError::InputAlreadyRead { input_name } => {
+                write!(f, "could not parse elves, {} was already read", input_name)
+            }
+        }
+    }
+}
+
+impl error::Error for ElvesParsingError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ElvesParsingError::Io { source, .. } => Some(source),
+            ElvesParsingError::InvalidNumber { source, .. } => Some(source),
+            ElvesParsingError::Overflow { source, .. } => Some(source),
+            ElvesParsingError::EmptyInput => None,
+            ElvesParsingError::InputAlreadyRead { .. } => None,
+        }
+    }
+}
+
+impl PartialEq for ElvesParsingError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                ElvesParsingError::Io { input_name, source },
+                ElvesParsingError::Io {
+                    input_name: other_input_name,
+                    source: other_source,
+                },
+            ) => {
+                input_name == other_input_name
+                    && source.kind() == other_source.kind()
+                    && source.to_string() == other_source.to_string()
+            }
+            (
+                ElvesParsingError::InvalidNumber {
+                    line,
+                    column,
+                    text,
+                    source,
+                },
+                ElvesParsingError::InvalidNumber {
+                    line: other_line,
+                    column: other_column,
+                    text: other_text,
+                    source: other_source,
+                },
+            ) => {
+                line == other_line
+                    && column == other_column
+                    && text == other_text
+                    && source == other_source
+            }
+            (
+                ElvesParsingError::Overflow { line, source },
+                ElvesParsingError::Overflow {
+                    line: other_line,
+                    source: other_source,
+                },
+            ) => line == other_line && source == other_source,
+            (ElvesParsingError::EmptyInput, ElvesParsingError::EmptyInput) => true,
+            (
+                ElvesParsingError::InputAlreadyRead { input_name },
+                ElvesParsingError::InputAlreadyRead {
+                    input_name: other_input_name,
+                },
+            ) => input_name == other_input_name,
+            _ => false,
+        }
+    }
+}
+
+impl ElvesParsingError {
+    pub fn build_io_error(input_name: &str, source: io::Error) -> Self {
+        ElvesParsingError::Io {
+            input_name: String::from(input_name),
+            source,
+        }
+    }
+
+    pub fn build_invalid_number_error(
+        line: usize,
+        column: usize,
+        text: &str,
+        source: num::ParseIntError,
+    ) -> Self {
+        ElvesParsingError::InvalidNumber {
+            line,
+            column,
+            text: String::from(text),
+            source,
+        }
+    }
+
+    pub fn build_input_already_read_error(input_name: &str) -> Self {
+        ElvesParsingError::InputAlreadyRead {
+            input_name: String::from(input_name),
+        }
+    }
+
+    pub fn build_overflow_error(line: Option<usize>, source: CaloriesOverflowError) -> Self {
+        ElvesParsingError::Overflow { line, source }
+    }
+}
@@ -0,0 +1,429 @@
+use crate::{
+    elf::{Calories, CaloriesOverflowError, Elf},
+    parser::ElvesParsingError,
+};
+use std::fmt::Write;
+
+const PERCENTILES: [f64; 6] = [10.0, 25.0, 50.0, 75.0, 90.0, 99.0];
+const HISTOGRAM_BAR_WIDTH: usize = 40;
+
+#[derive(Debug, PartialEq)]
+pub struct HistogramBin {
+    lower_calories: Calories,
+    upper_calories: Calories,
+    number_of_elves: usize,
+}
+
+impl HistogramBin {
+    pub fn get_lower_calories(&self) -> Calories {
+        self.lower_calories
+    }
+
+    pub fn get_upper_calories(&self) -> Calories {
+        self.upper_calories
+    }
+
+    pub fn get_number_of_elves(&self) -> usize {
+        self.number_of_elves
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InventoryStatistics {
+    sorted_calories: Vec<Calories>,
+    total_calories: Calories,
+}
+
+impl InventoryStatistics {
+    pub fn try_build(elves: &[Elf]) -> Result<Self, ElvesParsingError> {
+        if elves.is_empty() {
+            return Err(ElvesParsingError::EmptyInput);
+        }
+
+        let mut sorted_calories: Vec<Calories> =
+            elves.iter().map(|elf| elf.get_calories()).collect();
+        sorted_calories.sort();
+
+        let mut total_calories: Calories = 0;
+        for calories in sorted_calories.iter() {
+            total_calories = match total_calories.checked_add(*calories) {
+                Some(total_calories) => total_calories,
+                None => {
+                    return Err(ElvesParsingError::build_overflow_error(
+                        None,
+                        CaloriesOverflowError::build(total_calories, *calories),
+                    ))
+                }
+            };
+        }
+
+        Ok(InventoryStatistics {
+            sorted_calories,
+            total_calories,
+        })
+    }
+
+    pub fn get_number_of_elves(&self) -> usize {
+        self.sorted_calories.len()
+    }
+
+    pub fn get_total_calories(&self) -> Calories {
+        self.total_calories
+    }
+
+    pub fn get_min_calories(&self) -> Calories {
+        self.sorted_calories[0]
+    }
+
+    pub fn get_max_calories(&self) -> Calories {
+        self.sorted_calories[self.sorted_calories.len() - 1]
+    }
+
+    pub fn get_mean_calories(&self) -> f64 {
+        self.total_calories as f64 / self.get_number_of_elves() as f64
+    }
+
+    pub fn get_median_calories(&self) -> f64 {
+        self.get_percentile(50.0)
+    }
+
+    pub fn get_percentile(&self, percentile: f64) -> f64 {
+        let rank = percentile.clamp(0.0, 100.0) / 100.0 * (self.get_number_of_elves() - 1) as f64;
+        let lower_index = rank.floor() as usize;
+        let upper_index = rank.ceil() as usize;
+
+        let lower_calories = self.sorted_calories[lower_index] as f64;
+        let upper_calories = self.sorted_calories[upper_index] as f64;
+
+        lower_calories + (upper_calories - lower_calories) * (rank - lower_index as f64)
+    }
+
+    pub fn get_standard_deviation(&self) -> f64 {
+        let mean_calories = self.get_mean_calories();
+
+        let variance = self
+            .sorted_calories
+            .iter()
+            .map(|calories| (*calories as f64 - mean_calories).powi(2))
+            .sum::<f64>()
+            / self.get_number_of_elves() as f64;
+
+        variance.sqrt()
+    }
+
+    // at most the given number of bins, the bins past the max calories are left out so that the
+    // last bin ends at the max calories
+    pub fn get_histogram(&self, number_of_bins: usize) -> Vec<HistogramBin> {
+        let number_of_bins = Calories::try_from(number_of_bins.max(1)).unwrap_or(Calories::MAX);
+        let min_calories = self.get_min_calories();
+        let max_calories = self.get_max_calories();
+        let range = max_calories - min_calories;
+
+        // the ceiling of (range + 1) / number of bins, which only saturates for a single bin over
+        // all calories, hence the last bin index is bounded by the number of bins
+        let bin_width = (range / number_of_bins).saturating_add(1);
+        let last_bin_index = (range / bin_width).min(number_of_bins - 1);
+
+        let mut histogram: Vec<HistogramBin> = (0..=last_bin_index)
+            .map(|bin_index| {
+                let lower_calories = min_calories + bin_index * bin_width;
+                let upper_calories = if bin_index == last_bin_index {
+                    max_calories
+                } else {
+                    lower_calories + (bin_width - 1)
+                };
+
+                HistogramBin {
+                    lower_calories,
+                    upper_calories,
+                    number_of_elves: 0,
+                }
+            })
+            .collect();
+
+        for calories in self.sorted_calories.iter() {
+            let bin_index = usize::try_from((*calories - min_calories) / bin_width)
+                .unwrap_or(usize::MAX)
+                .min(histogram.len() - 1);
+            histogram[bin_index].number_of_elves += 1;
+        }
+
+        histogram
+    }
+
+    pub fn format_table(&self, number_of_bins: usize) -> String {
+        let mut table = String::new();
+
+        let _ = writeln!(table, "{:<20} {:>16}", "elves", self.get_number_of_elves());
+        let _ = writeln!(table, "{:<20} {:>16}", "total", self.get_total_calories());
+        let _ = writeln!(table, "{:<20} {:>16}", "min", self.get_min_calories());
+        let _ = writeln!(table, "{:<20} {:>16}", "max", self.get_max_calories());
+        let _ = writeln!(table, "{:<20} {:>16.2}", "mean", self.get_mean_calories());
+        let _ = writeln!(
+            table,
+            "{:<20} {:>16.2}",
+            "median",
+            self.get_median_calories()
+        );
+        let _ = writeln!(
+            table,
+            "{:<20} {:>16.2}",
+            "standard deviation",
+            self.get_standard_deviation()
+        );
+
+        for percentile in PERCENTILES {
+            let _ = writeln!(
+                table,
+                "{:<20} {:>16.2}",
+                format!("p{}", percentile),
+                self.get_percentile(percentile)
+            );
+        }
+
+        let histogram = self.get_histogram(number_of_bins);
+        let max_number_of_elves = histogram
+            .iter()
+            .map(|bin| bin.get_number_of_elves())
+            .max()
+            .unwrap_or_default()
+            .max(1);
+
+        let _ = writeln!(table);
+        for bin in histogram {
+            let bar_width = bin.get_number_of_elves() * HISTOGRAM_BAR_WIDTH / max_number_of_elves;
+            let _ = writeln!(
+                table,
+                "{:>10} - {:>10} | {:<width$} {}",
+                bin.get_lower_calories(),
+                bin.get_upper_calories(),
+                "#".repeat(bar_width),
+                bin.get_number_of_elves(),
+                width = HISTOGRAM_BAR_WIDTH
+            );
+        }
+
+        table
+    }
+
+    pub fn format_json(&self, number_of_bins: usize) -> String {
+        let percentiles: Vec<String> = PERCENTILES
+            .iter()
+            .map(|percentile| format!("\"p{}\":{}", percentile, self.get_percentile(*percentile)))
+            .collect();
+
+        let histogram: Vec<String> = self
+            .get_histogram(number_of_bins)
+            .iter()
+            .map(|bin| {
+                format!(
+                    "{{\"lower_calories\":{},\"upper_calories\":{},\"number_of_elves\":{}}}",
+                    bin.get_lower_calories(),
+                    bin.get_upper_calories(),
+                    bin.get_number_of_elves()
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"number_of_elves\":{},\"total_calories\":{},\"min_calories\":{},\"max_calories\":{},\"mean_calories\":{},\"median_calories\":{},\"standard_deviation\":{},\"percentiles\":{{{}}},\"histogram\":[{}]}}",
+            self.get_number_of_elves(),
+            self.get_total_calories(),
+            self.get_min_calories(),
+            self.get_max_calories(),
+            self.get_mean_calories(),
+            self.get_median_calories(),
+            self.get_standard_deviation(),
+            percentiles.join(","),
+            histogram.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elf::Snack;
+
+    fn build_elves(calories_list: Vec<Calories>) -> Vec<Elf> {
+        calories_list
+            .into_iter()
+            .map(|calories| {
+                let mut elf = Elf::new();
+                elf.try_add_snack(Snack::build(calories, 0)).unwrap();
+                elf
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_no_elves_statistics() {
+        assert_eq!(
+            InventoryStatistics::try_build(&[]),
+            Err(ElvesParsingError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_overflowing_total_statistics() {
+        let elves = build_elves(vec![Calories::MAX, 1]);
+
+        assert_eq!(
+            InventoryStatistics::try_build(&elves),
+            Err(ElvesParsingError::build_overflow_error(
+                None,
+                CaloriesOverflowError::build(1, Calories::MAX)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_one_elf_statistics() {
+        let statistics = InventoryStatistics::try_build(&build_elves(vec![500])).unwrap();
+
+        assert_eq!(statistics.get_number_of_elves(), 1);
+        assert_eq!(statistics.get_total_calories(), 500);
+        assert_eq!(statistics.get_mean_calories(), 500.0);
+        assert_eq!(statistics.get_median_calories(), 500.0);
+        assert_eq!(statistics.get_percentile(99.0), 500.0);
+        assert_eq!(statistics.get_standard_deviation(), 0.0);
+    }
+
+    #[test]
+    fn test_multiple_elves_statistics() {
+        let elves = build_elves(vec![6000, 4000, 11000, 24000, 10000]);
+        let statistics = InventoryStatistics::try_build(&elves).unwrap();
+
+        assert_eq!(statistics.get_number_of_elves(), 5);
+        assert_eq!(statistics.get_total_calories(), 55000);
+        assert_eq!(statistics.get_min_calories(), 4000);
+        assert_eq!(statistics.get_max_calories(), 24000);
+        assert_eq!(statistics.get_mean_calories(), 11000.0);
+        assert_eq!(statistics.get_median_calories(), 10000.0);
+        assert_eq!(statistics.get_standard_deviation(), 48_800_000_f64.sqrt());
+    }
+
+    #[test]
+    fn test_percentiles_are_interpolated() {
+        let statistics =
+            InventoryStatistics::try_build(&build_elves(vec![100, 200, 300, 400])).unwrap();
+
+        assert_eq!(statistics.get_percentile(0.0), 100.0);
+        assert_eq!(statistics.get_percentile(25.0), 175.0);
+        assert_eq!(statistics.get_percentile(50.0), 250.0);
+        assert_eq!(statistics.get_percentile(100.0), 400.0);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let elves = build_elves(vec![0, 1, 2, 3, 4, 5, 9]);
+        let statistics = InventoryStatistics::try_build(&elves).unwrap();
+
+        let expected_histogram = vec![
+            HistogramBin {
+                lower_calories: 0,
+                upper_calories: 3,
+                number_of_elves: 4,
+            },
+            HistogramBin {
+                lower_calories: 4,
+                upper_calories: 7,
+                number_of_elves: 2,
+            },
+            HistogramBin {
+                lower_calories: 8,
+                upper_calories: 9,
+                number_of_elves: 1,
+            },
+        ];
+
+        assert_eq!(statistics.get_histogram(3), expected_histogram);
+    }
+
+    #[test]
+    fn test_histogram_ends_at_max_calories() {
+        let calories_lists = [
+            vec![0, 1, 2, 3, 4, 5, 9],
+            vec![0, 5],
+            vec![3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+            vec![100, 300],
+        ];
+
+        for calories_list in calories_lists {
+            let max_calories = calories_list.iter().copied().max().unwrap();
+            let statistics = InventoryStatistics::try_build(&build_elves(calories_list)).unwrap();
+
+            for number_of_bins in 1..=12 {
+                let histogram = statistics.get_histogram(number_of_bins);
+                let last_bin = histogram.last().unwrap();
+
+                assert!(histogram.len() <= number_of_bins);
+                assert_eq!(last_bin.get_upper_calories(), max_calories);
+                assert_ne!(last_bin.get_number_of_elves(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_histogram_of_equal_elves() {
+        let statistics = InventoryStatistics::try_build(&build_elves(vec![7, 7, 7])).unwrap();
+
+        let expected_histogram = vec![HistogramBin {
+            lower_calories: 7,
+            upper_calories: 7,
+            number_of_elves: 3,
+        }];
+
+        assert_eq!(statistics.get_histogram(4), expected_histogram);
+    }
+
+    #[test]
+    fn test_histogram_of_all_calories() {
+        let statistics =
+            InventoryStatistics::try_build(&build_elves(vec![0, Calories::MAX])).unwrap();
+
+        let expected_histogram = vec![HistogramBin {
+            lower_calories: 0,
+            upper_calories: Calories::MAX,
+            number_of_elves: 2,
+        }];
+
+        assert_eq!(statistics.get_histogram(1), expected_histogram);
+
+        let histogram = statistics.get_histogram(2);
+
+        assert_eq!(histogram[0].get_number_of_elves(), 1);
+        assert_eq!(histogram[1].get_number_of_elves(), 1);
+        assert_eq!(histogram[1].get_upper_calories(), Calories::MAX);
+    }
+
+    #[test]
+    fn test_json_format() {
+        let statistics = InventoryStatistics::try_build(&build_elves(vec![100, 300])).unwrap();
+
+        assert_eq!(
+            statistics.format_json(1),
+            concat!(
+                "{\"number_of_elves\":2,\"total_calories\":400,\"min_calories\":100,",
+                "\"max_calories\":300,\"mean_calories\":200,\"median_calories\":200,",
+                "\"standard_deviation\":100,\"percentiles\":{\"p10\":120,\"p25\":150,",
+                "\"p50\":200,\"p75\":250,\"p90\":280,\"p99\":298},\"histogram\":",
+                "[{\"lower_calories\":100,\"upper_calories\":300,\"number_of_elves\":2}]}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_table_format() {
+        let statistics = InventoryStatistics::try_build(&build_elves(vec![100, 300])).unwrap();
+
+        let table = statistics.format_table(2);
+
+        assert!(table.starts_with("elves                               2\n"));
+        assert!(table.contains("median                         200.00\n"));
+        assert!(table.ends_with(&format!(
+            "       201 -        300 | {:<40} 1\n",
+            "#".repeat(40)
+        )));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    elf::{Calories, Elf},
+    leaderboard::{Leaderboard, LeaderboardEntry},
+    parser::{ElfGrouping, ElvesParser, ElvesParsingError},
+    try_sum_calories,
+};
+use std::io;
+
+pub struct CalorieTracker {
+    elves_parser: ElvesParser<'static>,
+    leaderboard: Leaderboard,
+}
+
+impl CalorieTracker {
+    pub fn build() -> Self {
+        CalorieTracker {
+            elves_parser: ElvesParser::build_from_reader(io::empty()),
+            leaderboard: Leaderboard::build(3),
+        }
+    }
+
+    pub fn with_grouping(mut self, grouping: ElfGrouping) -> Self {
+        self.elves_parser = self.elves_parser.with_grouping(grouping);
+        self
+    }
+
+    pub fn try_push_line(&mut self, line: &str) -> Result<(), ElvesParsingError> {
+        let leaderboard = &mut self.leaderboard;
+
+        self.elves_parser
+            .try_parse_line(line, &mut |elf_index, elf: Elf| {
+                leaderboard.add(LeaderboardEntry::build(elf_index, elf.get_calories()))
+            })
+    }
+
+    pub fn get_number_of_elves(&self) -> usize {
+        let number_of_current_elves = match self.elves_parser.get_current_elf_if_it_is_complete() {
+            Some(_) => 1,
+            None => 0,
+        };
+
+        self.elves_parser.get_number_of_finished_elves() + number_of_current_elves
+    }
+
+    pub fn get_top_three(&self) -> Vec<LeaderboardEntry> {
+        let mut leaderboard = self.leaderboard.clone();
+
+        if let Some((elf_index, elf)) = self.elves_parser.get_current_elf_if_it_is_complete() {
+            leaderboard.add(LeaderboardEntry::build(elf_index, elf.get_calories()));
+        }
+
+        leaderboard.get_entries()
+    }
+
+    pub fn try_get_max_calories_from_one_elf(&self) -> Result<Calories, ElvesParsingError> {
+        match self.get_top_three().first() {
+            Some(entry) => Ok(entry.get_calories()),
+            None => Err(ElvesParsingError::EmptyInput),
+        }
+    }
+
+    pub fn try_get_max_calories_from_three_elves(&self) -> Result<Calories, ElvesParsingError> {
+        let entries = self.get_top_three();
+
+        if entries.is_empty() {
+            return Err(ElvesParsingError::EmptyInput);
+        }
+
+        try_sum_calories(&entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        get_max_calories_from_one_elf_from_reader, get_max_calories_from_three_elves_from_reader,
+        InventoryGenerator,
+    };
+
+    fn build_tracker(lines: Vec<&str>, grouping: ElfGrouping) -> CalorieTracker {
+        let mut tracker = CalorieTracker::build().with_grouping(grouping);
+        for line in lines {
+            tracker.try_push_line(line).unwrap();
+        }
+        tracker
+    }
+
+    #[test]
+    fn test_empty_tracker() {
+        let tracker = CalorieTracker::build();
+
+        assert_eq!(tracker.get_number_of_elves(), 0);
+        assert_eq!(tracker.get_top_three(), vec![]);
+        assert_eq!(
+            tracker.try_get_max_calories_from_one_elf(),
+            Err(ElvesParsingError::EmptyInput)
+        );
+        assert_eq!(
+            tracker.try_get_max_calories_from_three_elves(),
+            Err(ElvesParsingError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_tracker_includes_current_elf() {
+        let mut tracker = build_tracker(vec!["1000", "2000", ""], ElfGrouping::default());
+
+        assert_eq!(tracker.get_number_of_elves(), 1);
+        assert_eq!(tracker.try_get_max_calories_from_one_elf(), Ok(3000));
+
+        tracker.try_push_line("4000").unwrap();
+
+        assert_eq!(tracker.get_number_of_elves(), 2);
+        assert_eq!(tracker.try_get_max_calories_from_one_elf(), Ok(4000));
+        assert_eq!(tracker.try_get_max_calories_from_three_elves(), Ok(7000));
+        assert_eq!(
+            tracker.get_top_three(),
+            vec![
+                LeaderboardEntry::build(1, 4000),
+                LeaderboardEntry::build(0, 3000)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tracker_keeps_top_three() {
+        let tracker = build_tracker(
+            vec!["100", "", "400", "", "200", "", "500", "", "300"],
+            ElfGrouping::default(),
+        );
+
+        assert_eq!(tracker.get_number_of_elves(), 5);
+        assert_eq!(
+            tracker.get_top_three(),
+            vec![
+                LeaderboardEntry::build(3, 500),
+                LeaderboardEntry::build(1, 400),
+                LeaderboardEntry::build(4, 300)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tracker_groupings() {
+        let lines = vec!["0", "", "", "100", ""];
+
+        let merge_zero_calories_tracker =
+            build_tracker(lines.clone(), ElfGrouping::MergeZeroCalories);
+        let non_empty_groups_tracker = build_tracker(lines.clone(), ElfGrouping::NonEmptyGroups);
+        let all_groups_tracker = build_tracker(lines, ElfGrouping::AllGroups);
+
+        assert_eq!(merge_zero_calories_tracker.get_number_of_elves(), 1);
+        assert_eq!(non_empty_groups_tracker.get_number_of_elves(), 2);
+        assert_eq!(all_groups_tracker.get_number_of_elves(), 3);
+    }
+
+    #[test]
+    fn test_tracker_invalid_line() {
+        let mut tracker = build_tracker(vec!["100"], ElfGrouping::default());
+
+        assert_eq!(
+            tracker.try_push_line(" 1x0"),
+            Err(ElvesParsingError::build_invalid_number_error(
+                2,
+                3,
+                "1x0",
+                "1x0".parse::<Calories>().unwrap_err()
+            ))
+        );
+
+        tracker.try_push_line("200").unwrap();
+
+        assert_eq!(tracker.try_get_max_calories_from_one_elf(), Ok(300));
+    }
+
+    #[test]
+    fn test_tracker_matches_results_after_every_line() {
+        for seed in 0..20 {
+            let (input, _) = InventoryGenerator::build(seed)
+                .with_number_of_elves(8)
+                .with_snacks_per_elf(1, 4)
+                .try_generate()
+                .unwrap();
+
+            let mut tracker = CalorieTracker::build();
+            let mut pushed_input = String::new();
+
+            for line in input.lines() {
+                tracker.try_push_line(line).unwrap();
+                pushed_input.push_str(line);
+                pushed_input.push('\n');
+
+                assert_eq!(
+                    tracker.try_get_max_calories_from_one_elf(),
+                    get_max_calories_from_one_elf_from_reader(pushed_input.as_bytes())
+                );
+                assert_eq!(
+                    tracker.try_get_max_calories_from_three_elves(),
+                    get_max_calories_from_three_elves_from_reader(pushed_input.as_bytes())
+                );
+            }
+        }
+    }
+}
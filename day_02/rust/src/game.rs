@@ -1,86 +1,102 @@
+pub mod optimizer;
 pub mod parser;
 pub mod parser_functional;
-mod result;
-mod shape;
-mod variant;
+pub mod report;
+pub mod result;
+pub mod rules;
+pub mod scoring;
+pub mod shape;
+pub mod simulation;
+pub mod source;
+mod tokenizer;
+pub mod tournament;
+pub mod variant;
+pub mod writer;
 
 use result::Result;
+use rules::RulesError;
+use scoring::ScoringRules;
 use shape::Shape;
 
-#[derive(Debug, PartialEq)]
+// both variants keep what follows from the rules, the own shape of a V2 game and the result of
+// a V1 game, so that they can be scored without the rules
+#[derive(Debug, Clone, PartialEq)]
 pub enum Game {
     V1 {
         opponent_shape: Shape,
         own_shape: Shape,
+        result: Result,
     },
     V2 {
         opponent_shape: Shape,
+        own_shape: Shape,
         result: Result,
     },
 }
 
 impl Game {
-    fn build_v1(opponent_shape: Shape, own_shape: Shape) -> Self {
-        Game::V1 {
+    // the own shape has to play by the rules of the opponent shape
+    fn try_build_v1(
+        opponent_shape: Shape,
+        own_shape: Shape,
+    ) -> std::result::Result<Self, RulesError> {
+        let result = opponent_shape
+            .get_rules()
+            .try_get_game_result(&opponent_shape, &own_shape)?;
+
+        Ok(Game::V1 {
             opponent_shape,
             own_shape,
-        }
+            result,
+        })
     }
 
     fn build_v2(opponent_shape: Shape, result: Result) -> Self {
         Game::V2 {
+            own_shape: opponent_shape.get_shape_for_result(&result),
             opponent_shape,
             result,
         }
     }
 
-    pub fn get_points(&self) -> u32 {
-        self.get_own_shape().get_points() + self.get_game_result().get_points()
+    pub fn to_v1(&self) -> Self {
+        Game::V1 {
+            opponent_shape: self.get_opponent_shape(),
+            own_shape: self.get_own_shape(),
+            result: self.get_game_result(),
+        }
+    }
+
+    pub fn to_v2(&self) -> Self {
+        Game::V2 {
+            opponent_shape: self.get_opponent_shape(),
+            own_shape: self.get_own_shape(),
+            result: self.get_game_result(),
+        }
+    }
+
+    pub fn get_points(&self, scoring_rules: &ScoringRules) -> u32 {
+        scoring_rules.get_shape_points(&self.get_own_shape())
+            + scoring_rules.get_result_points(&self.get_game_result())
     }
 
-    fn get_own_shape(&self) -> &Shape {
+    fn get_opponent_shape(&self) -> Shape {
         match self {
-            Game::V1 {
-                opponent_shape: _,
-                own_shape,
-            } => own_shape,
-            Game::V2 {
-                opponent_shape,
-                result,
-            } => match (opponent_shape, result) {
-                (Shape::Rock, Result::Loss) => &Shape::Scissors,
-                (Shape::Rock, Result::Tie) => &Shape::Rock,
-                (Shape::Rock, Result::Win) => &Shape::Paper,
-                (Shape::Paper, Result::Loss) => &Shape::Rock,
-                (Shape::Paper, Result::Tie) => &Shape::Paper,
-                (Shape::Paper, Result::Win) => &Shape::Scissors,
-                (Shape::Scissors, Result::Loss) => &Shape::Paper,
-                (Shape::Scissors, Result::Tie) => &Shape::Scissors,
-                (Shape::Scissors, Result::Win) => &Shape::Rock,
-            },
+            Game::V1 { opponent_shape, .. } | Game::V2 { opponent_shape, .. } => {
+                opponent_shape.clone()
+            }
         }
     }
 
-    fn get_game_result(&self) -> &Result {
+    fn get_own_shape(&self) -> Shape {
         match self {
-            Game::V1 {
-                opponent_shape,
-                own_shape,
-            } => match (opponent_shape, own_shape) {
-                (Shape::Rock, Shape::Rock) => &Result::Tie,
-                (Shape::Rock, Shape::Paper) => &Result::Win,
-                (Shape::Rock, Shape::Scissors) => &Result::Loss,
-                (Shape::Paper, Shape::Rock) => &Result::Loss,
-                (Shape::Paper, Shape::Paper) => &Result::Tie,
-                (Shape::Paper, Shape::Scissors) => &Result::Win,
-                (Shape::Scissors, Shape::Rock) => &Result::Win,
-                (Shape::Scissors, Shape::Paper) => &Result::Loss,
-                (Shape::Scissors, Shape::Scissors) => &Result::Tie,
-            },
-            Game::V2 {
-                opponent_shape: _,
-                result,
-            } => result,
+            Game::V1 { own_shape, .. } | Game::V2 { own_shape, .. } => own_shape.clone(),
+        }
+    }
+
+    fn get_game_result(&self) -> Result {
+        match self {
+            Game::V1 { result, .. } | Game::V2 { result, .. } => *result,
         }
     }
 }
@@ -91,181 +107,196 @@ mod tests {
 
     #[test]
     fn test_rock_rock_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Rock,
-            own_shape: Shape::Rock,
-        };
+        let game = Game::try_build_v1(Shape::ROCK, Shape::ROCK).unwrap();
         assert_eq!(game.get_game_result().get_points(), 3);
-        assert_eq!(game.get_points(), 4);
+        assert_eq!(game.get_points(&ScoringRules::default()), 4);
     }
 
     #[test]
     fn test_rock_paper_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Rock,
-            own_shape: Shape::Paper,
-        };
+        let game = Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap();
         assert_eq!(game.get_game_result().get_points(), 6);
-        assert_eq!(game.get_points(), 8);
+        assert_eq!(game.get_points(&ScoringRules::default()), 8);
     }
 
     #[test]
     fn test_rock_scissors_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Rock,
-            own_shape: Shape::Scissors,
-        };
+        let game = Game::try_build_v1(Shape::ROCK, Shape::SCISSORS).unwrap();
         assert_eq!(game.get_game_result().get_points(), 0);
-        assert_eq!(game.get_points(), 3);
+        assert_eq!(game.get_points(&ScoringRules::default()), 3);
     }
 
     #[test]
     fn test_paper_rock_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Paper,
-            own_shape: Shape::Rock,
-        };
+        let game = Game::try_build_v1(Shape::PAPER, Shape::ROCK).unwrap();
         assert_eq!(game.get_game_result().get_points(), 0);
-        assert_eq!(game.get_points(), 1);
+        assert_eq!(game.get_points(&ScoringRules::default()), 1);
     }
 
     #[test]
     fn test_paper_paper_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Paper,
-            own_shape: Shape::Paper,
-        };
+        let game = Game::try_build_v1(Shape::PAPER, Shape::PAPER).unwrap();
         assert_eq!(game.get_game_result().get_points(), 3);
-        assert_eq!(game.get_points(), 5);
+        assert_eq!(game.get_points(&ScoringRules::default()), 5);
     }
 
     #[test]
     fn test_paper_scissors_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Paper,
-            own_shape: Shape::Scissors,
-        };
+        let game = Game::try_build_v1(Shape::PAPER, Shape::SCISSORS).unwrap();
         assert_eq!(game.get_game_result().get_points(), 6);
-        assert_eq!(game.get_points(), 9);
+        assert_eq!(game.get_points(&ScoringRules::default()), 9);
     }
 
     #[test]
     fn test_scissors_rock_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Scissors,
-            own_shape: Shape::Rock,
-        };
+        let game = Game::try_build_v1(Shape::SCISSORS, Shape::ROCK).unwrap();
         assert_eq!(game.get_game_result().get_points(), 6);
-        assert_eq!(game.get_points(), 7);
+        assert_eq!(game.get_points(&ScoringRules::default()), 7);
     }
 
     #[test]
     fn test_scissors_paper_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Scissors,
-            own_shape: Shape::Paper,
-        };
+        let game = Game::try_build_v1(Shape::SCISSORS, Shape::PAPER).unwrap();
         assert_eq!(game.get_game_result().get_points(), 0);
-        assert_eq!(game.get_points(), 2);
+        assert_eq!(game.get_points(&ScoringRules::default()), 2);
     }
 
     #[test]
     fn test_scissors_scissors_points() {
-        let game = Game::V1 {
-            opponent_shape: Shape::Scissors,
-            own_shape: Shape::Scissors,
-        };
+        let game = Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap();
         assert_eq!(game.get_game_result().get_points(), 3);
-        assert_eq!(game.get_points(), 6);
+        assert_eq!(game.get_points(&ScoringRules::default()), 6);
     }
 
     #[test]
     fn test_rock_loss_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Rock,
-            result: Result::Loss,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 3);
-        assert_eq!(game.get_points(), 3);
+        let game = Game::build_v2(Shape::ROCK, Result::Loss);
+        assert_eq!(game.get_own_shape(), Shape::SCISSORS);
+        assert_eq!(game.get_points(&ScoringRules::default()), 3);
     }
 
     #[test]
     fn test_rock_tie_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Rock,
-            result: Result::Tie,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 1);
-        assert_eq!(game.get_points(), 4);
+        let game = Game::build_v2(Shape::ROCK, Result::Tie);
+        assert_eq!(game.get_own_shape(), Shape::ROCK);
+        assert_eq!(game.get_points(&ScoringRules::default()), 4);
     }
 
     #[test]
     fn test_rock_win_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Rock,
-            result: Result::Win,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 2);
-        assert_eq!(game.get_points(), 8);
+        let game = Game::build_v2(Shape::ROCK, Result::Win);
+        assert_eq!(game.get_own_shape(), Shape::PAPER);
+        assert_eq!(game.get_points(&ScoringRules::default()), 8);
     }
 
     #[test]
     fn test_paper_loss_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Paper,
-            result: Result::Loss,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 1);
-        assert_eq!(game.get_points(), 1);
+        let game = Game::build_v2(Shape::PAPER, Result::Loss);
+        assert_eq!(game.get_own_shape(), Shape::ROCK);
+        assert_eq!(game.get_points(&ScoringRules::default()), 1);
     }
 
     #[test]
     fn test_paper_tie_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Paper,
-            result: Result::Tie,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 2);
-        assert_eq!(game.get_points(), 5);
+        let game = Game::build_v2(Shape::PAPER, Result::Tie);
+        assert_eq!(game.get_own_shape(), Shape::PAPER);
+        assert_eq!(game.get_points(&ScoringRules::default()), 5);
     }
 
     #[test]
     fn test_paper_win_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Paper,
-            result: Result::Win,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 3);
-        assert_eq!(game.get_points(), 9);
+        let game = Game::build_v2(Shape::PAPER, Result::Win);
+        assert_eq!(game.get_own_shape(), Shape::SCISSORS);
+        assert_eq!(game.get_points(&ScoringRules::default()), 9);
     }
 
     #[test]
     fn test_scissors_loss_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Scissors,
-            result: Result::Loss,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 2);
-        assert_eq!(game.get_points(), 2);
+        let game = Game::build_v2(Shape::SCISSORS, Result::Loss);
+        assert_eq!(game.get_own_shape(), Shape::PAPER);
+        assert_eq!(game.get_points(&ScoringRules::default()), 2);
     }
 
     #[test]
     fn test_scissors_tie_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Scissors,
-            result: Result::Tie,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 3);
-        assert_eq!(game.get_points(), 6);
+        let game = Game::build_v2(Shape::SCISSORS, Result::Tie);
+        assert_eq!(game.get_own_shape(), Shape::SCISSORS);
+        assert_eq!(game.get_points(&ScoringRules::default()), 6);
     }
 
     #[test]
     fn test_scissors_win_points() {
-        let game = Game::V2 {
-            opponent_shape: Shape::Scissors,
-            result: Result::Win,
-        };
-        assert_eq!(game.get_own_shape().get_points(), 1);
-        assert_eq!(game.get_points(), 7);
+        let game = Game::build_v2(Shape::SCISSORS, Result::Win);
+        assert_eq!(game.get_own_shape(), Shape::ROCK);
+        assert_eq!(game.get_points(&ScoringRules::default()), 7);
+    }
+
+    #[test]
+    fn test_v1_to_v2_conversion() {
+        let game = Game::try_build_v1(Shape::SCISSORS, Shape::PAPER).unwrap();
+
+        assert_eq!(game.to_v2(), Game::build_v2(Shape::SCISSORS, Result::Loss));
+        assert_eq!(game.to_v1(), game);
+    }
+
+    #[test]
+    fn test_v2_to_v1_conversion() {
+        let game = Game::build_v2(Shape::PAPER, Result::Win);
+
+        assert_eq!(
+            game.to_v1(),
+            Game::try_build_v1(Shape::PAPER, Shape::SCISSORS).unwrap()
+        );
+        assert_eq!(game.to_v2(), game);
+    }
+
+    #[test]
+    fn test_conversions_keep_points() {
+        let scoring_rules = ScoringRules::default();
+
+        for opponent_shape in Shape::all() {
+            for own_shape in Shape::all() {
+                let game = Game::try_build_v1(opponent_shape.clone(), own_shape).unwrap();
+
+                assert_eq!(game.to_v2().to_v1(), game);
+                assert_eq!(
+                    game.to_v2().get_points(&scoring_rules),
+                    game.get_points(&scoring_rules)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_results_agree_with_own_shapes() {
+        for opponent_shape in Shape::all() {
+            for result in Result::all() {
+                let own_shape = Game::build_v2(opponent_shape.clone(), result).get_own_shape();
+
+                assert_eq!(
+                    Game::try_build_v1(opponent_shape.clone(), own_shape.clone())
+                        .unwrap()
+                        .get_game_result(),
+                    result
+                );
+                assert_eq!(
+                    Game::try_build_v1(own_shape, opponent_shape.clone())
+                        .unwrap()
+                        .get_game_result(),
+                    result.inverse()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_scoring_rules_points() {
+        let scoring_rules = ScoringRules::try_build([10, 20, 30], [1, 2, 3]).unwrap();
+
+        let game = Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap();
+        assert_eq!(game.get_points(&scoring_rules), 23);
+
+        let game = Game::build_v2(Shape::ROCK, Result::Loss);
+        assert_eq!(game.get_points(&scoring_rules), 31);
     }
 }
@@ -0,0 +1,259 @@
+use super::{
+    result::Result as GameResult, rules::GameRules, scoring::ScoringRules, shape::Shape, Game,
+};
+use std::fmt;
+
+const COLUMN_LETTERS: [&str; 3] = ["X", "Y", "Z"];
+
+const PERMUTATIONS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mapping {
+    Shapes([Shape; 3]),
+    Results([GameResult; 3]),
+}
+
+impl Mapping {
+    pub fn get_all_shape_mappings() -> Vec<Mapping> {
+        PERMUTATIONS
+            .iter()
+            .map(|permutation| {
+                Mapping::Shapes(permutation.map(|index| Shape::all()[index].clone()))
+            })
+            .collect()
+    }
+
+    pub fn get_all_result_mappings() -> Vec<Mapping> {
+        PERMUTATIONS
+            .iter()
+            .map(|permutation| Mapping::Results(permutation.map(|index| GameResult::all()[index])))
+            .collect()
+    }
+
+    fn try_get_game(&self, guide_game: &Game) -> Option<Game> {
+        let (opponent_shape, own_shape) = match guide_game {
+            Game::V1 {
+                opponent_shape,
+                own_shape,
+                ..
+            } => (opponent_shape.clone(), own_shape),
+            Game::V2 { .. } => return None,
+        };
+
+        // the guide is parsed as variant 1, so the own shape tells which letter was in the second
+        // column, X for rock, Y for paper and Z for scissors, games of other rules are skipped
+        if !GameRules::get_rock_paper_scissors().contains(own_shape) {
+            return None;
+        }
+
+        let column_index = own_shape.get_index();
+
+        let game = match self {
+            Mapping::Shapes(shapes) => {
+                Game::try_build_v1(opponent_shape, shapes.get(column_index)?.clone()).ok()?
+            }
+            Mapping::Results(results) => {
+                Game::build_v2(opponent_shape, *results.get(column_index)?)
+            }
+        };
+
+        Some(game)
+    }
+
+    fn get_points(&self, guide_games: &[Game], scoring_rules: &ScoringRules) -> u64 {
+        guide_games
+            .iter()
+            .filter_map(|guide_game| self.try_get_game(guide_game))
+            .map(|game| u64::from(game.get_points(scoring_rules)))
+            .sum()
+    }
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values: Vec<String> = match self {
+            Mapping::Shapes(shapes) => shapes.iter().map(|shape| shape.to_string()).collect(),
+            Mapping::Results(results) => results.iter().map(|result| result.to_string()).collect(),
+        };
+
+        let columns: Vec<String> = COLUMN_LETTERS
+            .iter()
+            .zip(values)
+            .map(|(letter, value)| format!("{}={}", letter, value))
+            .collect();
+
+        write!(f, "{}", columns.join(", "))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MappingScore {
+    mapping: Mapping,
+    points: u64,
+}
+
+impl MappingScore {
+    pub fn get_mapping(&self) -> &Mapping {
+        &self.mapping
+    }
+
+    pub fn get_points(&self) -> u64 {
+        self.points
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MappingScores {
+    scores: Vec<MappingScore>,
+}
+
+impl MappingScores {
+    pub fn build(
+        guide_games: &[Game],
+        mappings: Vec<Mapping>,
+        scoring_rules: &ScoringRules,
+    ) -> Self {
+        let scores = mappings
+            .into_iter()
+            .map(|mapping| MappingScore {
+                points: mapping.get_points(guide_games, scoring_rules),
+                mapping,
+            })
+            .collect();
+
+        MappingScores { scores }
+    }
+
+    pub fn get_scores(&self) -> &[MappingScore] {
+        &self.scores
+    }
+
+    pub fn get_best(&self) -> Option<&MappingScore> {
+        self.scores
+            .iter()
+            .rev()
+            .max_by_key(|score| score.get_points())
+    }
+
+    pub fn get_worst(&self) -> Option<&MappingScore> {
+        self.scores.iter().min_by_key(|score| score.get_points())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_guide_games() -> Vec<Game> {
+        vec![
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::try_build_v1(Shape::PAPER, Shape::ROCK).unwrap(),
+            Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_all_mappings_are_different() {
+        let shape_mappings = Mapping::get_all_shape_mappings();
+        let result_mappings = Mapping::get_all_result_mappings();
+
+        assert_eq!(shape_mappings.len(), 6);
+        assert_eq!(result_mappings.len(), 6);
+
+        for (index, mapping) in shape_mappings.iter().enumerate() {
+            assert!(!shape_mappings[index + 1..].contains(mapping));
+        }
+        for (index, mapping) in result_mappings.iter().enumerate() {
+            assert!(!result_mappings[index + 1..].contains(mapping));
+        }
+    }
+
+    #[test]
+    fn test_mapping_display() {
+        let mapping = Mapping::Shapes([Shape::SCISSORS, Shape::ROCK, Shape::PAPER]);
+        assert_eq!(mapping.to_string(), "X=Scissors, Y=Rock, Z=Paper");
+
+        let mapping = Mapping::Results([GameResult::Loss, GameResult::Tie, GameResult::Win]);
+        assert_eq!(mapping.to_string(), "X=Loss, Y=Tie, Z=Win");
+    }
+
+    #[test]
+    fn test_default_mappings_points() {
+        let guide_games = build_guide_games();
+
+        let shape_mapping = Mapping::Shapes([Shape::ROCK, Shape::PAPER, Shape::SCISSORS]);
+        let result_mapping = Mapping::Results([GameResult::Loss, GameResult::Tie, GameResult::Win]);
+
+        assert_eq!(
+            shape_mapping.get_points(&guide_games, &ScoringRules::default()),
+            15
+        );
+        assert_eq!(
+            result_mapping.get_points(&guide_games, &ScoringRules::default()),
+            12
+        );
+    }
+
+    #[test]
+    fn test_shape_mapping_scores() {
+        let scores = MappingScores::build(
+            &build_guide_games(),
+            Mapping::get_all_shape_mappings(),
+            &ScoringRules::default(),
+        );
+
+        let points: Vec<u64> = scores
+            .get_scores()
+            .iter()
+            .map(|score| score.get_points())
+            .collect();
+
+        assert_eq!(points, vec![15, 6, 15, 15, 15, 24]);
+        assert_eq!(
+            scores.get_best().unwrap().get_mapping(),
+            &Mapping::Shapes([Shape::SCISSORS, Shape::PAPER, Shape::ROCK])
+        );
+        assert_eq!(
+            scores.get_worst().unwrap().get_mapping(),
+            &Mapping::Shapes([Shape::ROCK, Shape::SCISSORS, Shape::PAPER])
+        );
+    }
+
+    #[test]
+    fn test_best_and_worst_result_mappings() {
+        let scores = MappingScores::build(
+            &build_guide_games(),
+            Mapping::get_all_result_mappings(),
+            &ScoringRules::default(),
+        );
+
+        let best = scores.get_best().unwrap();
+        let worst = scores.get_worst().unwrap();
+
+        assert_eq!(
+            best.get_mapping(),
+            &Mapping::Results([GameResult::Win, GameResult::Loss, GameResult::Tie])
+        );
+        assert_eq!(best.get_points(), 18);
+        assert_eq!(
+            worst.get_mapping(),
+            &Mapping::Results([GameResult::Loss, GameResult::Tie, GameResult::Win])
+        );
+        assert_eq!(worst.get_points(), 12);
+    }
+
+    #[test]
+    fn test_no_mappings_scores() {
+        let scores = MappingScores::build(&build_guide_games(), vec![], &ScoringRules::default());
+
+        assert_eq!(scores.get_best(), None);
+        assert_eq!(scores.get_worst(), None);
+    }
+}
@@ -1,4 +1,10 @@
-use super::{result::Result as GameResult, shape::Shape, variant::Variant as GameVariant, Game};
+use super::{
+    rules::GameRules,
+    source::{GameSource, ParsingMode},
+    tokenizer::{self, Token},
+    variant::Variant as GameVariant,
+    Game,
+};
 use std::{
     error, fmt, fs,
     io::{self, BufRead},
@@ -14,6 +20,25 @@ impl ParsingError {
     pub fn build(cause: String) -> Self {
         ParsingError { cause }
     }
+
+    pub fn build_line_error(file_name: &str, line_index: usize, cause: String) -> Self {
+        Self::build(format!(
+            "error in file '{}' on line {}, {}",
+            file_name, line_index, cause
+        ))
+    }
+
+    pub fn build_column_error(
+        file_name: &str,
+        line_index: usize,
+        column_index: usize,
+        cause: String,
+    ) -> Self {
+        Self::build(format!(
+            "error in file '{}' on line {}, column {}, {}",
+            file_name, line_index, column_index, cause
+        ))
+    }
 }
 
 impl fmt::Display for ParsingError {
@@ -29,49 +54,40 @@ pub struct Parser {
     current_line: String,
     current_line_index: usize,
     game_variant: GameVariant,
+    rules: GameRules,
+    mode: ParsingMode,
+    diagnostics: Vec<ParsingError>,
 }
 
 impl Parser {
-    pub fn build_v1(file_name: &str) -> Self {
+    pub fn build(file_name: &str, game_variant: GameVariant) -> Self {
         Parser {
             file_name: String::from(file_name),
             current_line: String::new(),
             current_line_index: 0,
-            game_variant: GameVariant::V1,
+            game_variant,
+            rules: GameRules::build_rock_paper_scissors(),
+            mode: ParsingMode::default(),
+            diagnostics: Vec::new(),
         }
     }
 
-    pub fn build_v2(file_name: &str) -> Self {
-        Parser {
-            file_name: String::from(file_name),
-            current_line: String::new(),
-            current_line_index: 0,
-            game_variant: GameVariant::V2,
-        }
+    pub fn build_v1(file_name: &str) -> Self {
+        Self::build(file_name, GameVariant::Shape)
     }
 
-    pub fn try_get_games(&mut self) -> Result<Vec<Game>, ParsingError> {
-        let file = self.try_open_file()?;
-        let mut games = Vec::new();
-
-        for line in file.lines() {
-            self.try_set_current_line_and_index(line)?;
-
-            let game = self.try_get_game()?;
-            games.push(game);
-        }
+    pub fn build_v2(file_name: &str) -> Self {
+        Self::build(file_name, GameVariant::Outcome)
+    }
 
-        Ok(games)
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
     }
 
-    fn try_open_file(&self) -> Result<io::BufReader<fs::File>, ParsingError> {
-        match fs::File::open(path::Path::new(&self.file_name)) {
-            Ok(file) => Ok(io::BufReader::new(file)),
-            Err(err) => Err(ParsingError::build(format!(
-                "could not open file '{}', {}",
-                self.file_name, err
-            ))),
-        }
+    pub fn with_mode(mut self, mode: ParsingMode) -> Self {
+        self.mode = mode;
+        self
     }
 
     fn try_set_current_line_and_index(
@@ -79,105 +95,104 @@ impl Parser {
         line: Result<String, io::Error>,
     ) -> Result<(), ParsingError> {
         self.current_line_index += 1;
-
-        self.current_line = match line {
-            Ok(line) => line.trim().to_string(),
-            Err(err) => {
-                return Err(ParsingError::build(format!(
-                    "error in file '{}' on line {}, {}",
-                    self.file_name, self.current_line_index, err
-                )))
-            }
-        };
+        self.current_line = try_get_line(&self.file_name, self.current_line_index, line)?;
 
         Ok(())
     }
 
-    fn try_get_game(&mut self) -> Result<Game, ParsingError> {
-        let game = match self.game_variant {
-            GameVariant::V1 => self.try_get_game_variant_1()?,
-            GameVariant::V2 => self.try_get_game_variant_2()?,
+    fn try_get_game(&self) -> Result<Option<Game>, ParsingError> {
+        let (opponent_input, input) = match self.try_get_game_inputs()? {
+            Some(inputs) => inputs,
+            None => return Ok(None),
         };
 
-        Ok(game)
-    }
-
-    fn try_get_game_variant_1(&mut self) -> Result<Game, ParsingError> {
-        let (opponent_shape, own_shape) = self.try_get_game_inputs()?;
+        let opponent_shape = self
+            .game_variant
+            .try_get_opponent_shape(&self.rules, opponent_input.get_input())
+            .map_err(|cause| self.build_column_error(&opponent_input, cause))?;
 
-        let opponent_shape = self.try_get_shape_game_variant_1(opponent_shape)?;
-        let own_shape = self.try_get_shape_game_variant_1(own_shape)?;
+        let game = self
+            .game_variant
+            .try_get_game(&self.rules, opponent_shape, input.get_input())
+            .map_err(|cause| self.build_column_error(&input, cause))?;
 
-        let game = Game::build_v1(opponent_shape, own_shape);
-
-        Ok(game)
+        Ok(Some(game))
     }
 
-    fn try_get_game_variant_2(&mut self) -> Result<Game, ParsingError> {
-        let (opponent_shape, result) = self.try_get_game_inputs()?;
+    fn try_get_game_inputs(&self) -> Result<Option<(Token<'_>, Token<'_>)>, ParsingError> {
+        let tokens = tokenizer::get_tokens(&self.current_line);
+
+        match tokens.as_slice() {
+            [] => Ok(None),
+            [opponent_input, input] => Ok(Some((*opponent_input, *input))),
+            [_, _, unexpected_input, ..] => Err(self.build_column_error(
+                unexpected_input,
+                format!("expected 2 inputs, got {}", tokens.len()),
+            )),
+            _ => Err(ParsingError::build_line_error(
+                &self.file_name,
+                self.current_line_index,
+                format!("expected 2 inputs, got {}", tokens.len()),
+            )),
+        }
+    }
 
-        let opponent_shape = self.try_get_shape_game_variant_2(opponent_shape)?;
-        let result = self.try_get_result_game_variant_2(result)?;
+    fn build_column_error(&self, token: &Token, cause: String) -> ParsingError {
+        ParsingError::build_column_error(
+            &self.file_name,
+            self.current_line_index,
+            token.get_column_index(),
+            cause,
+        )
+    }
+}
 
-        let game = Game::build_v2(opponent_shape, result);
+impl GameSource for Parser {
+    fn try_get_games_by_line(&mut self) -> Result<Vec<(usize, Game)>, ParsingError> {
+        let file = try_open_file(&self.file_name)?;
+        let mut games = Vec::new();
 
-        Ok(game)
-    }
+        self.current_line_index = 0;
+        self.diagnostics.clear();
 
-    fn try_get_game_inputs(&self) -> Result<(&str, &str), ParsingError> {
-        let splitted_line: Vec<&str> = self.current_line.split(' ').collect();
+        for line in file.lines() {
+            self.try_set_current_line_and_index(line)?;
 
-        match splitted_line.len() {
-            2 => Ok((splitted_line[0], splitted_line[1])),
-            _ => Err(ParsingError::build(format!(
-                "error in file '{}' on line {}, expected 2 inputs, got {}",
-                self.file_name,
-                self.current_line_index,
-                splitted_line.len()
-            ))),
+            match self.try_get_game() {
+                Ok(Some(game)) => games.push((self.current_line_index, game)),
+                Ok(None) => {}
+                Err(err) if self.mode == ParsingMode::Lenient => self.diagnostics.push(err),
+                Err(err) => return Err(err),
+            }
         }
-    }
 
-    fn try_get_shape_game_variant_1(&self, input: &str) -> Result<Shape, ParsingError> {
-        match input {
-            "A" | "X" => Ok(Shape::Rock),
-            "B" | "Y" => Ok(Shape::Paper),
-            "C" | "Z" => Ok(Shape::Scissors),
-            input => Err(ParsingError::build(format!(
-                "error in file '{}' on line {}, expected 'A', 'B', 'C', 'X', 'Y' or 'Z' for shape, got {}",
-                self.file_name, self.current_line_index, input
-            ))),
-        }
+        Ok(games)
     }
 
-    fn try_get_shape_game_variant_2(&self, input: &str) -> Result<Shape, ParsingError> {
-        match input {
-            "A" => Ok(Shape::Rock),
-            "B" => Ok(Shape::Paper),
-            "C" => Ok(Shape::Scissors),
-            input => Err(ParsingError::build(format!(
-                "error in file '{}' on line {}, expected 'A', 'B' or 'C' for shape, got {}",
-                self.file_name, self.current_line_index, input
-            ))),
-        }
+    fn get_diagnostics(&self) -> &[ParsingError] {
+        &self.diagnostics
     }
+}
 
-    fn try_get_result_game_variant_2(&self, input: &str) -> Result<GameResult, ParsingError> {
-        match input {
-            "X" => Ok(GameResult::Loss),
-            "Y" => Ok(GameResult::Tie),
-            "Z" => Ok(GameResult::Win),
-            input => Err(ParsingError::build(format!(
-                "error in file '{}' on line {}, expected 'X', 'Y' or 'Z' for result, got {}",
-                self.file_name, self.current_line_index, input
-            ))),
-        }
-    }
+// shared by every parser reading a guide or tournament file line by line
+pub(crate) fn try_open_file(file_name: &str) -> Result<io::BufReader<fs::File>, ParsingError> {
+    fs::File::open(path::Path::new(file_name))
+        .map(io::BufReader::new)
+        .map_err(|err| ParsingError::build(format!("could not open file '{}', {}", file_name, err)))
+}
+
+pub(crate) fn try_get_line(
+    file_name: &str,
+    line_index: usize,
+    line: Result<String, io::Error>,
+) -> Result<String, ParsingError> {
+    line.map_err(|err| ParsingError::build_line_error(file_name, line_index, err.to_string()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::{optimizer::Mapping, result::Result as GameResult, shape::Shape};
 
     #[test]
     fn test_structure_game_variant_1() {
@@ -187,9 +202,9 @@ mod tests {
         let games = game_variant_1_parser.try_get_games();
 
         let expected_games = vec![
-            Game::build_v1(Shape::Rock, Shape::Paper),
-            Game::build_v1(Shape::Paper, Shape::Rock),
-            Game::build_v1(Shape::Scissors, Shape::Scissors),
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::try_build_v1(Shape::PAPER, Shape::ROCK).unwrap(),
+            Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap(),
         ];
 
         assert_eq!(games, Ok(expected_games));
@@ -210,6 +225,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_input_game_variant_1() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let mut game_variant_1_parser = Parser::build_v1(file_name);
+        let games = game_variant_1_parser.try_get_games();
+
+        assert_eq!(
+            games,
+            Err(ParsingError::build(format!(
+                "error in file '{}' on line 2, expected 2 inputs, got 1",
+                file_name,
+            )))
+        );
+    }
+
+    #[test]
+    fn test_lenient_invalid_input_game_variant_2() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let mut game_variant_2_parser = Parser::build_v2(file_name).with_mode(ParsingMode::Lenient);
+        let games = game_variant_2_parser.try_get_games();
+
+        let expected_games = vec![
+            Game::build_v2(Shape::ROCK, GameResult::Tie),
+            Game::build_v2(Shape::SCISSORS, GameResult::Win),
+        ];
+
+        let expected_diagnostics = vec![
+            ParsingError::build(format!(
+                "error in file '{}' on line 2, expected 2 inputs, got 1",
+                file_name,
+            )),
+            ParsingError::build(format!(
+                "error in file '{}' on line 4, column 1, expected 'A', 'B' or 'C' for shape, got D",
+                file_name,
+            )),
+        ];
+
+        assert_eq!(games, Ok(expected_games));
+        assert_eq!(
+            game_variant_2_parser.get_diagnostics(),
+            expected_diagnostics
+        );
+    }
+
     #[test]
     fn test_structure_game_variant_2() {
         let file_name = "../input/test_input.txt";
@@ -218,9 +279,83 @@ mod tests {
         let games = game_variant_2_parser.try_get_games();
 
         let expected_games = vec![
-            Game::build_v2(Shape::Rock, GameResult::Tie),
-            Game::build_v2(Shape::Paper, GameResult::Loss),
-            Game::build_v2(Shape::Scissors, GameResult::Win),
+            Game::build_v2(Shape::ROCK, GameResult::Tie),
+            Game::build_v2(Shape::PAPER, GameResult::Loss),
+            Game::build_v2(Shape::SCISSORS, GameResult::Win),
+        ];
+
+        assert_eq!(games, Ok(expected_games));
+    }
+
+    #[test]
+    fn test_whitespace_and_comments_game_variant_1() {
+        let file_name = "../input/test_input_whitespace.txt";
+
+        let mut game_variant_1_parser = Parser::build_v1(file_name);
+        let games = game_variant_1_parser.try_get_games_by_line();
+
+        let expected_games = vec![
+            (3, Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap()),
+            (5, Game::try_build_v1(Shape::PAPER, Shape::ROCK).unwrap()),
+            (
+                6,
+                Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap(),
+            ),
+        ];
+
+        assert_eq!(games, Ok(expected_games));
+    }
+
+    #[test]
+    fn test_invalid_columns_game_variant_2() {
+        let file_name = "../input/test_input_invalid_columns.txt";
+
+        let mut game_variant_2_parser = Parser::build_v2(file_name).with_mode(ParsingMode::Lenient);
+        let games = game_variant_2_parser.try_get_games();
+
+        let expected_diagnostics = vec![
+            ParsingError::build_column_error(
+                file_name,
+                1,
+                5,
+                String::from("expected 'X', 'Y' or 'Z' for result, got C"),
+            ),
+            ParsingError::build_column_error(
+                file_name,
+                2,
+                5,
+                String::from("expected 2 inputs, got 3"),
+            ),
+            ParsingError::build_column_error(
+                file_name,
+                4,
+                3,
+                String::from("expected 'A', 'B' or 'C' for shape, got Q"),
+            ),
+        ];
+
+        assert_eq!(
+            games,
+            Ok(vec![Game::build_v2(Shape::PAPER, GameResult::Loss)])
+        );
+        assert_eq!(
+            game_variant_2_parser.get_diagnostics(),
+            expected_diagnostics
+        );
+    }
+
+    #[test]
+    fn test_structure_result_mapping() {
+        let file_name = "../input/test_input.txt";
+
+        let mapping = Mapping::Results([GameResult::Win, GameResult::Tie, GameResult::Loss]);
+        let mut parser = Parser::build(file_name, GameVariant::Mapping(mapping));
+        let games = parser.try_get_games();
+
+        let expected_games = vec![
+            Game::build_v2(Shape::ROCK, GameResult::Tie),
+            Game::build_v2(Shape::PAPER, GameResult::Win),
+            Game::build_v2(Shape::SCISSORS, GameResult::Loss),
         ];
 
         assert_eq!(games, Ok(expected_games));
@@ -240,4 +375,96 @@ mod tests {
             )))
         );
     }
+
+    #[test]
+    fn test_structure_rock_paper_scissors_lizard_spock_rules_game_variant_1() {
+        let file_name = "../input/test_input_rock_paper_scissors_lizard_spock.txt";
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        let mut parser = Parser::build_v1(file_name).with_rules(rules.clone());
+        let games = parser.try_get_games();
+
+        let expected_games = vec![
+            Game::try_build_v1(Shape::build(0, &rules), Shape::build(4, &rules)).unwrap(),
+            Game::try_build_v1(Shape::build(3, &rules), Shape::build(1, &rules)).unwrap(),
+            Game::try_build_v1(Shape::build(4, &rules), Shape::build(2, &rules)).unwrap(),
+            Game::try_build_v1(Shape::build(1, &rules), Shape::build(1, &rules)).unwrap(),
+        ];
+
+        assert_eq!(games, Ok(expected_games));
+    }
+
+    #[test]
+    fn test_whitespace_and_comments_rock_paper_scissors_lizard_spock_rules_game_variant_1() {
+        let file_name = "../input/test_input_rock_paper_scissors_lizard_spock_whitespace.txt";
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        let mut parser = Parser::build_v1(file_name)
+            .with_rules(rules.clone())
+            .with_mode(ParsingMode::Lenient);
+        let games = parser.try_get_games_by_line();
+
+        let expected_games = vec![
+            (
+                3,
+                Game::try_build_v1(Shape::build(0, &rules), Shape::build(4, &rules)).unwrap(),
+            ),
+            (
+                5,
+                Game::try_build_v1(Shape::build(3, &rules), Shape::build(1, &rules)).unwrap(),
+            ),
+            (
+                6,
+                Game::try_build_v1(Shape::build(4, &rules), Shape::build(2, &rules)).unwrap(),
+            ),
+        ];
+
+        assert_eq!(games, Ok(expected_games));
+        assert_eq!(
+            parser.get_diagnostics(),
+            vec![ParsingError::build_column_error(
+                file_name,
+                7,
+                5,
+                String::from("expected 2 inputs, got 3"),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_invalid_result_rock_paper_scissors_lizard_spock_rules_game_variant_2() {
+        let file_name = "../input/test_input_rock_paper_scissors_lizard_spock.txt";
+
+        let mut parser = Parser::build_v2(file_name)
+            .with_rules(GameRules::build_rock_paper_scissors_lizard_spock());
+        let games = parser.try_get_games();
+
+        assert_eq!(
+            games,
+            Err(ParsingError::build_column_error(
+                file_name,
+                2,
+                3,
+                String::from("expected 'X', 'Y' or 'Z' for result, got W"),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_shape_rock_paper_scissors_rules_game_variant_1() {
+        let file_name = "../input/test_input_rock_paper_scissors_lizard_spock.txt";
+
+        let mut parser = Parser::build_v1(file_name);
+        let games = parser.try_get_games();
+
+        assert_eq!(
+            games,
+            Err(ParsingError::build_column_error(
+                file_name,
+                2,
+                1,
+                String::from("expected 'A', 'B', 'C', 'X', 'Y' or 'Z' for shape, got D"),
+            ))
+        );
+    }
 }
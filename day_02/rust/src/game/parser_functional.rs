@@ -1,157 +1,325 @@
-use super::{result::Result as GameResult, shape::Shape, variant::Variant as GameVariant, Game};
-use std::{
-    fs,
-    io::{self, BufRead},
-    path,
+use super::{
+    parser::{self, ParsingError},
+    rules::GameRules,
+    source::{GameSource, ParsingMode},
+    tokenizer::{self, Token},
+    variant::Variant as GameVariant,
+    Game,
 };
-
-pub fn try_get_games_variant_1(file_name: &str) -> Option<Vec<Game>> {
-    try_get_games(file_name, GameVariant::V1)
-}
-
-pub fn try_get_games_variant_2(file_name: &str) -> Option<Vec<Game>> {
-    try_get_games(file_name, GameVariant::V2)
-}
-
-fn try_get_games(file_name: &str, game_variant: GameVariant) -> Option<Vec<Game>> {
-    let file = try_open_file(file_name)?;
-
-    let games = file
-        .lines()
-        .map(try_get_line)
-        .filter_map(|line| try_get_game(line, &game_variant))
-        .collect();
-
-    Some(games)
+use std::io::BufRead;
+
+pub struct FunctionalParser {
+    file_name: String,
+    game_variant: GameVariant,
+    rules: GameRules,
+    mode: ParsingMode,
+    diagnostics: Vec<ParsingError>,
 }
 
-fn try_open_file(file_name: &str) -> Option<io::BufReader<fs::File>> {
-    match fs::File::open(path::Path::new(file_name)) {
-        Ok(file) => Some(io::BufReader::new(file)),
-        Err(_) => None,
+impl FunctionalParser {
+    pub fn build_v1(file_name: &str) -> Self {
+        Self::build(file_name, GameVariant::Shape)
     }
-}
 
-fn try_get_line(line: Result<String, io::Error>) -> Option<String> {
-    match line {
-        Ok(line) => Some(line.trim().to_string()),
-        Err(_) => None,
+    pub fn build_v2(file_name: &str) -> Self {
+        Self::build(file_name, GameVariant::Outcome)
     }
-}
 
-fn try_get_game(line: Option<String>, game_variant: &GameVariant) -> Option<Game> {
-    match line {
-        Some(line) => match game_variant {
-            GameVariant::V1 => try_get_game_variant_1(line),
-            GameVariant::V2 => try_get_game_variant_2(line),
-        },
-        None => None,
+    pub fn build(file_name: &str, game_variant: GameVariant) -> Self {
+        FunctionalParser {
+            file_name: String::from(file_name),
+            game_variant,
+            rules: GameRules::build_rock_paper_scissors(),
+            mode: ParsingMode::default(),
+            diagnostics: Vec::new(),
+        }
     }
-}
-
-fn try_get_game_variant_1(line: String) -> Option<Game> {
-    let (opponent_shape, own_shape) = try_get_game_inputs(line)?;
-
-    let opponent_shape = try_get_shape_game_variant_1(&opponent_shape)?;
-    let own_shape = try_get_shape_game_variant_1(&own_shape)?;
 
-    Some(Game::build_v1(opponent_shape, own_shape))
-}
-
-fn try_get_game_variant_2(line: String) -> Option<Game> {
-    let (opponent_shape, result) = try_get_game_inputs(line)?;
-
-    let opponent_shape = try_get_shape_game_variant_2(&opponent_shape)?;
-    let result = try_get_result_game_variant_2(&result)?;
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
 
-    Some(Game::build_v2(opponent_shape, result))
+    pub fn with_mode(mut self, mode: ParsingMode) -> Self {
+        self.mode = mode;
+        self
+    }
 }
 
-fn try_get_game_inputs(line: String) -> Option<(String, String)> {
-    let splitted_line: Vec<String> = line.split(' ').map(String::from).collect();
-
-    match splitted_line.len() {
-        2 => Some((splitted_line[0].to_owned(), splitted_line[1].to_owned())),
-        _ => None,
+impl GameSource for FunctionalParser {
+    fn try_get_games_by_line(&mut self) -> Result<Vec<(usize, Game)>, ParsingError> {
+        let file = parser::try_open_file(&self.file_name)?;
+        let file_name = &self.file_name;
+        let game_variant = &self.game_variant;
+        let rules = &self.rules;
+
+        let mut game_results = file
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                parser::try_get_line(file_name, index + 1, line).map(|line| (index + 1, line))
+            })
+            .map(|line| {
+                line.map(|(line_index, line)| {
+                    try_get_game(file_name, line_index, &line, game_variant, rules)
+                        .map(|game| game.map(|game| (line_index, game)))
+                })
+            });
+
+        let diagnostics = &mut self.diagnostics;
+        diagnostics.clear();
+
+        match self.mode {
+            ParsingMode::Strict => game_results
+                .filter_map(|game| game.and_then(|game| game).transpose())
+                .collect(),
+            ParsingMode::Lenient => game_results.try_fold(Vec::new(), |mut games, game| {
+                match game? {
+                    Ok(Some(game)) => games.push(game),
+                    Ok(None) => {}
+                    Err(err) => diagnostics.push(err),
+                }
+                Ok(games)
+            }),
+        }
     }
-}
 
-fn try_get_shape_game_variant_1(input: &str) -> Option<Shape> {
-    match input {
-        "A" | "X" => Some(Shape::Rock),
-        "B" | "Y" => Some(Shape::Paper),
-        "C" | "Z" => Some(Shape::Scissors),
-        _ => None,
+    fn get_diagnostics(&self) -> &[ParsingError] {
+        &self.diagnostics
     }
 }
 
-fn try_get_shape_game_variant_2(input: &str) -> Option<Shape> {
-    match input {
-        "A" => Some(Shape::Rock),
-        "B" => Some(Shape::Paper),
-        "C" => Some(Shape::Scissors),
-        _ => None,
-    }
+fn try_get_game(
+    file_name: &str,
+    line_index: usize,
+    line: &str,
+    game_variant: &GameVariant,
+    rules: &GameRules,
+) -> Result<Option<Game>, ParsingError> {
+    let build_column_error = |token: &Token, cause| {
+        ParsingError::build_column_error(file_name, line_index, token.get_column_index(), cause)
+    };
+
+    try_get_game_inputs(file_name, line_index, line)?
+        .map(|(opponent_input, input)| {
+            game_variant
+                .try_get_opponent_shape(rules, opponent_input.get_input())
+                .map_err(|cause| build_column_error(&opponent_input, cause))
+                .and_then(|opponent_shape| {
+                    game_variant
+                        .try_get_game(rules, opponent_shape, input.get_input())
+                        .map_err(|cause| build_column_error(&input, cause))
+                })
+        })
+        .transpose()
 }
 
-fn try_get_result_game_variant_2(input: &str) -> Option<GameResult> {
-    match input {
-        "X" => Some(GameResult::Loss),
-        "Y" => Some(GameResult::Tie),
-        "Z" => Some(GameResult::Win),
-        _ => None,
+fn try_get_game_inputs<'a>(
+    file_name: &str,
+    line_index: usize,
+    line: &'a str,
+) -> Result<Option<(Token<'a>, Token<'a>)>, ParsingError> {
+    let tokens = tokenizer::get_tokens(line);
+
+    match tokens.as_slice() {
+        [] => Ok(None),
+        [opponent_input, input] => Ok(Some((*opponent_input, *input))),
+        [_, _, unexpected_input, ..] => Err(ParsingError::build_column_error(
+            file_name,
+            line_index,
+            unexpected_input.get_column_index(),
+            format!("expected 2 inputs, got {}", tokens.len()),
+        )),
+        _ => Err(ParsingError::build_line_error(
+            file_name,
+            line_index,
+            format!("expected 2 inputs, got {}", tokens.len()),
+        )),
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::{
+        optimizer::Mapping, parser::Parser, result::Result as GameResult, shape::Shape,
+    };
     use super::*;
 
     #[test]
     fn test_structure_game_variant_1() {
         let file_name = "../input/test_input.txt";
 
-        let games = try_get_games_variant_1(file_name);
+        let games = FunctionalParser::build_v1(file_name).try_get_games();
 
         let expected_games = vec![
-            Game::build_v1(Shape::Rock, Shape::Paper),
-            Game::build_v1(Shape::Paper, Shape::Rock),
-            Game::build_v1(Shape::Scissors, Shape::Scissors),
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::try_build_v1(Shape::PAPER, Shape::ROCK).unwrap(),
+            Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap(),
         ];
 
-        assert_eq!(games, Some(expected_games));
+        assert_eq!(games, Ok(expected_games));
     }
 
     #[test]
     fn test_missing_input_file_game_variant_1() {
         let file_name = "../input/wrong_test_input.txt";
 
-        let games = try_get_games_variant_1(file_name);
+        let games = FunctionalParser::build_v1(file_name).try_get_games();
 
-        assert_eq!(games, None);
+        assert_eq!(
+            games,
+            Err(ParsingError::build(format!(
+                "could not open file '{}', No such file or directory (os error 2)",
+                file_name,
+            )))
+        );
     }
 
     #[test]
     fn test_structure_game_variant_2() {
         let file_name = "../input/test_input.txt";
 
-        let games = try_get_games_variant_2(file_name);
+        let games = FunctionalParser::build_v2(file_name).try_get_games();
 
         let expected_games = vec![
-            Game::build_v2(Shape::Rock, GameResult::Tie),
-            Game::build_v2(Shape::Paper, GameResult::Loss),
-            Game::build_v2(Shape::Scissors, GameResult::Win),
+            Game::build_v2(Shape::ROCK, GameResult::Tie),
+            Game::build_v2(Shape::PAPER, GameResult::Loss),
+            Game::build_v2(Shape::SCISSORS, GameResult::Win),
         ];
 
-        assert_eq!(games, Some(expected_games));
+        assert_eq!(games, Ok(expected_games));
+    }
+
+    #[test]
+    fn test_parsers_agree_on_shape_mapping() {
+        let file_name = "../input/test_input.txt";
+        let variant = GameVariant::Mapping(Mapping::Shapes([
+            Shape::PAPER,
+            Shape::SCISSORS,
+            Shape::ROCK,
+        ]));
+
+        let games = FunctionalParser::build(file_name, variant.clone()).try_get_games();
+
+        assert_eq!(games, Parser::build(file_name, variant).try_get_games());
+        assert_eq!(
+            games.unwrap()[0],
+            Game::try_build_v1(Shape::ROCK, Shape::SCISSORS).unwrap()
+        );
     }
 
     #[test]
     fn test_missing_input_file_game_variant_2() {
         let file_name = "../input/wrong_test_input.txt";
 
-        let games = try_get_games_variant_2(file_name);
+        let games = FunctionalParser::build_v2(file_name).try_get_games();
+
+        assert_eq!(
+            games,
+            Err(ParsingError::build(format!(
+                "could not open file '{}', No such file or directory (os error 2)",
+                file_name,
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parsers_agree_on_invalid_input() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let sources: Vec<(Box<dyn GameSource>, Box<dyn GameSource>)> = vec![
+            (
+                Box::new(Parser::build_v1(file_name)),
+                Box::new(FunctionalParser::build_v1(file_name)),
+            ),
+            (
+                Box::new(Parser::build_v2(file_name)),
+                Box::new(FunctionalParser::build_v2(file_name)),
+            ),
+            (
+                Box::new(Parser::build_v1(file_name).with_mode(ParsingMode::Lenient)),
+                Box::new(FunctionalParser::build_v1(file_name).with_mode(ParsingMode::Lenient)),
+            ),
+            (
+                Box::new(Parser::build_v2(file_name).with_mode(ParsingMode::Lenient)),
+                Box::new(FunctionalParser::build_v2(file_name).with_mode(ParsingMode::Lenient)),
+            ),
+        ];
+
+        for (mut parser, mut functional_parser) in sources {
+            assert_eq!(parser.try_get_games(), functional_parser.try_get_games());
+            assert_eq!(
+                parser.get_diagnostics(),
+                functional_parser.get_diagnostics()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parsers_agree_on_whitespace_and_comments() {
+        for file_name in [
+            "../input/test_input_whitespace.txt",
+            "../input/test_input_invalid_columns.txt",
+        ] {
+            let mut parser = Parser::build_v2(file_name).with_mode(ParsingMode::Lenient);
+            let mut functional_parser =
+                FunctionalParser::build_v2(file_name).with_mode(ParsingMode::Lenient);
+
+            assert_eq!(
+                parser.try_get_games_by_line(),
+                functional_parser.try_get_games_by_line()
+            );
+            assert_eq!(
+                parser.get_diagnostics(),
+                functional_parser.get_diagnostics()
+            );
+        }
+    }
+
+    #[test]
+    fn test_lenient_game_variant_1() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let mut parser = FunctionalParser::build_v1(file_name).with_mode(ParsingMode::Lenient);
+        let games = parser.try_get_games();
+
+        let expected_games = vec![
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap(),
+        ];
 
-        assert_eq!(games, None);
+        let expected_diagnostics = vec![
+            ParsingError::build_line_error(file_name, 2, String::from("expected 2 inputs, got 1")),
+            ParsingError::build_column_error(
+                file_name,
+                4,
+                1,
+                String::from("expected 'A', 'B', 'C', 'X', 'Y' or 'Z' for shape, got D"),
+            ),
+        ];
+
+        assert_eq!(games, Ok(expected_games));
+        assert_eq!(parser.get_diagnostics(), expected_diagnostics);
+    }
+
+    #[test]
+    fn test_parsers_agree_on_other_rules() {
+        let file_name = "../input/test_input_rock_paper_scissors_lizard_spock.txt";
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        for variant in [GameVariant::Shape, GameVariant::Outcome] {
+            let mut parser = Parser::build(file_name, variant.clone())
+                .with_rules(rules.clone())
+                .with_mode(ParsingMode::Lenient);
+            let mut functional_parser = FunctionalParser::build(file_name, variant)
+                .with_rules(rules.clone())
+                .with_mode(ParsingMode::Lenient);
+
+            assert_eq!(parser.try_get_games(), functional_parser.try_get_games());
+            assert_eq!(
+                parser.get_diagnostics(),
+                functional_parser.get_diagnostics()
+            );
+        }
     }
 }
@@ -0,0 +1,362 @@
+use super::{result::Result as GameResult, scoring::ScoringRules, shape::Shape, Game};
+use serde_json::json;
+use std::{fmt::Write, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "table" => Ok(ReportFormat::Table),
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            input => Err(format!(
+                "expected 'table', 'csv' or 'json' for report format, got {}",
+                input
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Round {
+    line_index: usize,
+    opponent_shape: Shape,
+    own_shape: Shape,
+    result: GameResult,
+    shape_points: u32,
+    result_points: u32,
+}
+
+impl Round {
+    fn build(line_index: usize, game: &Game, scoring_rules: &ScoringRules) -> Self {
+        let own_shape = game.get_own_shape();
+        let result = game.get_game_result();
+
+        Round {
+            line_index,
+            opponent_shape: game.get_opponent_shape(),
+            shape_points: scoring_rules.get_shape_points(&own_shape),
+            own_shape,
+            result,
+            result_points: scoring_rules.get_result_points(&result),
+        }
+    }
+
+    pub fn get_line_index(&self) -> usize {
+        self.line_index
+    }
+
+    pub fn get_points(&self) -> u32 {
+        self.shape_points + self.result_points
+    }
+
+    fn is_win(&self) -> bool {
+        self.result == GameResult::Win
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GamesReport {
+    rounds: Vec<Round>,
+}
+
+impl GamesReport {
+    pub fn build(games: &[(usize, Game)], scoring_rules: &ScoringRules) -> Self {
+        let rounds = games
+            .iter()
+            .map(|(line_index, game)| Round::build(*line_index, game, scoring_rules))
+            .collect();
+
+        GamesReport { rounds }
+    }
+
+    pub fn get_rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    pub fn get_number_of_wins(&self) -> usize {
+        self.get_number_of_results(GameResult::Win)
+    }
+
+    pub fn get_number_of_ties(&self) -> usize {
+        self.get_number_of_results(GameResult::Tie)
+    }
+
+    pub fn get_number_of_losses(&self) -> usize {
+        self.get_number_of_results(GameResult::Loss)
+    }
+
+    pub fn get_shape_points(&self) -> u64 {
+        self.rounds
+            .iter()
+            .map(|round| u64::from(round.shape_points))
+            .sum()
+    }
+
+    pub fn get_result_points(&self) -> u64 {
+        self.rounds
+            .iter()
+            .map(|round| u64::from(round.result_points))
+            .sum()
+    }
+
+    pub fn get_total_points(&self) -> u64 {
+        self.get_shape_points() + self.get_result_points()
+    }
+
+    pub fn get_longest_win_streak(&self) -> usize {
+        self.rounds
+            .iter()
+            .fold((0, 0), |(longest_streak, streak), round| {
+                let streak = if round.is_win() { streak + 1 } else { 0 };
+                (longest_streak.max(streak), streak)
+            })
+            .0
+    }
+
+    pub fn format(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Table => self.format_table(),
+            ReportFormat::Csv => self.format_csv(),
+            ReportFormat::Json => self.format_json(),
+        }
+    }
+
+    fn get_number_of_results(&self, result: GameResult) -> usize {
+        self.rounds
+            .iter()
+            .filter(|round| round.result == result)
+            .count()
+    }
+
+    fn format_table(&self) -> String {
+        let mut table = String::new();
+
+        let _ = writeln!(
+            table,
+            "{:>6} {:<10} {:<10} {:<6} {:>6}",
+            "line", "opponent", "own", "result", "points"
+        );
+
+        for round in self.rounds.iter() {
+            let _ = writeln!(
+                table,
+                "{:>6} {:<10} {:<10} {:<6} {:>6}",
+                round.line_index,
+                round.opponent_shape.to_string(),
+                round.own_shape.to_string(),
+                round.result.to_string(),
+                round.get_points()
+            );
+        }
+
+        let _ = writeln!(table);
+        let _ = writeln!(table, "{:<20} {:>10}", "wins", self.get_number_of_wins());
+        let _ = writeln!(table, "{:<20} {:>10}", "ties", self.get_number_of_ties());
+        let _ = writeln!(
+            table,
+            "{:<20} {:>10}",
+            "losses",
+            self.get_number_of_losses()
+        );
+        let _ = writeln!(
+            table,
+            "{:<20} {:>10}",
+            "shape points",
+            self.get_shape_points()
+        );
+        let _ = writeln!(
+            table,
+            "{:<20} {:>10}",
+            "result points",
+            self.get_result_points()
+        );
+        let _ = writeln!(
+            table,
+            "{:<20} {:>10}",
+            "total points",
+            self.get_total_points()
+        );
+        let _ = writeln!(
+            table,
+            "{:<20} {:>10}",
+            "longest win streak",
+            self.get_longest_win_streak()
+        );
+
+        table
+    }
+
+    fn format_csv(&self) -> String {
+        let mut csv = String::from("line,opponent_shape,own_shape,result,points\n");
+
+        for round in self.rounds.iter() {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{}",
+                round.line_index,
+                round.opponent_shape,
+                round.own_shape,
+                round.result,
+                round.get_points()
+            );
+        }
+
+        csv
+    }
+
+    fn format_json(&self) -> String {
+        let rounds: Vec<serde_json::Value> = self
+            .rounds
+            .iter()
+            .map(|round| {
+                json!({
+                    "line": round.line_index,
+                    "opponent_shape": round.opponent_shape.to_string(),
+                    "own_shape": round.own_shape.to_string(),
+                    "result": round.result.to_string(),
+                    "points": round.get_points(),
+                })
+            })
+            .collect();
+
+        json!({
+            "rounds": rounds,
+            "wins": self.get_number_of_wins(),
+            "ties": self.get_number_of_ties(),
+            "losses": self.get_number_of_losses(),
+            "shape_points": self.get_shape_points(),
+            "result_points": self.get_result_points(),
+            "total_points": self.get_total_points(),
+            "longest_win_streak": self.get_longest_win_streak(),
+        })
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_report(games: Vec<Game>) -> GamesReport {
+        let games: Vec<(usize, Game)> = games
+            .into_iter()
+            .enumerate()
+            .map(|(index, game)| (index + 1, game))
+            .collect();
+
+        GamesReport::build(&games, &ScoringRules::default())
+    }
+
+    #[test]
+    fn test_report_aggregates() {
+        let report = build_report(vec![
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::try_build_v1(Shape::PAPER, Shape::ROCK).unwrap(),
+            Game::try_build_v1(Shape::SCISSORS, Shape::SCISSORS).unwrap(),
+        ]);
+
+        assert_eq!(report.get_rounds().len(), 3);
+        assert_eq!(report.get_number_of_wins(), 1);
+        assert_eq!(report.get_number_of_ties(), 1);
+        assert_eq!(report.get_number_of_losses(), 1);
+        assert_eq!(report.get_shape_points(), 6);
+        assert_eq!(report.get_result_points(), 9);
+        assert_eq!(report.get_total_points(), 15);
+    }
+
+    #[test]
+    fn test_report_points_beyond_u32() {
+        let scoring_rules = ScoringRules::try_build([1, 2, u32::MAX - 6], [0, 3, 6]).unwrap();
+        let game = Game::try_build_v1(Shape::PAPER, Shape::SCISSORS).unwrap();
+
+        let report = GamesReport::build(&[(1, game.clone()), (2, game)], &scoring_rules);
+
+        assert_eq!(report.get_rounds()[0].get_points(), u32::MAX);
+        assert_eq!(report.get_total_points(), 2 * u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn test_longest_win_streak() {
+        let report = build_report(vec![
+            Game::build_v2(Shape::ROCK, GameResult::Win),
+            Game::build_v2(Shape::ROCK, GameResult::Tie),
+            Game::build_v2(Shape::ROCK, GameResult::Win),
+            Game::build_v2(Shape::PAPER, GameResult::Win),
+            Game::build_v2(Shape::SCISSORS, GameResult::Win),
+            Game::build_v2(Shape::ROCK, GameResult::Loss),
+            Game::build_v2(Shape::ROCK, GameResult::Win),
+        ]);
+
+        assert_eq!(report.get_longest_win_streak(), 3);
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = build_report(vec![]);
+
+        assert_eq!(report.get_total_points(), 0);
+        assert_eq!(report.get_longest_win_streak(), 0);
+    }
+
+    #[test]
+    fn test_csv_format() {
+        let report = build_report(vec![
+            Game::build_v2(Shape::ROCK, GameResult::Tie),
+            Game::build_v2(Shape::PAPER, GameResult::Loss),
+        ]);
+
+        assert_eq!(
+            report.format(ReportFormat::Csv),
+            concat!(
+                "line,opponent_shape,own_shape,result,points\n",
+                "1,Rock,Rock,Tie,4\n",
+                "2,Paper,Rock,Loss,1\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_json_format() {
+        let report = build_report(vec![
+            Game::try_build_v1(Shape::SCISSORS, Shape::ROCK).unwrap()
+        ]);
+
+        assert_eq!(
+            report.format(ReportFormat::Json),
+            concat!(
+                "{\"longest_win_streak\":1,\"losses\":0,\"result_points\":6,",
+                "\"rounds\":[{\"line\":1,\"opponent_shape\":\"Scissors\",",
+                "\"own_shape\":\"Rock\",\"points\":7,\"result\":\"Win\"}],",
+                "\"shape_points\":1,\"ties\":0,\"total_points\":7,\"wins\":1}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_table_format() {
+        let report = build_report(vec![
+            Game::try_build_v1(Shape::SCISSORS, Shape::ROCK).unwrap()
+        ]);
+
+        let table = report.format(ReportFormat::Table);
+
+        assert!(table.starts_with("  line opponent   own        result points\n"));
+        assert!(table.contains("     1 Scissors   Rock       Win         7\n"));
+        assert!(table.ends_with("longest win streak            1\n"));
+    }
+
+    #[test]
+    fn test_report_format_from_str() {
+        assert_eq!("csv".parse::<ReportFormat>(), Ok(ReportFormat::Csv));
+        assert!("xml".parse::<ReportFormat>().is_err());
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Result {
     Loss,
     Tie,
@@ -6,6 +8,10 @@ pub enum Result {
 }
 
 impl Result {
+    pub fn all() -> [Result; 3] {
+        [Result::Loss, Result::Tie, Result::Win]
+    }
+
     pub fn get_points(&self) -> u32 {
         match self {
             Result::Loss => 0,
@@ -13,6 +19,42 @@ impl Result {
             Result::Win => 6,
         }
     }
+
+    pub fn inverse(&self) -> Result {
+        match self {
+            Result::Loss => Result::Win,
+            Result::Tie => Result::Tie,
+            Result::Win => Result::Loss,
+        }
+    }
+}
+
+impl FromStr for Result {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input {
+            "X" => Ok(Result::Loss),
+            "Y" => Ok(Result::Tie),
+            "Z" => Ok(Result::Win),
+            input => Err(format!(
+                "expected 'X', 'Y' or 'Z' for result, got {}",
+                input
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Result {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Result::Loss => "Loss",
+            Result::Tie => "Tie",
+            Result::Win => "Win",
+        };
+
+        write!(f, "{}", name)
+    }
 }
 
 #[cfg(test)]
@@ -36,4 +78,21 @@ mod tests {
         let win = Result::Win;
         assert_eq!(win.get_points(), 6);
     }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(Result::Loss.inverse(), Result::Win);
+        assert_eq!(Result::Tie.inverse(), Result::Tie);
+        assert_eq!(Result::Win.inverse(), Result::Loss);
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        assert_eq!("Z".parse::<Result>(), Ok(Result::Win));
+        assert_eq!(
+            "A".parse::<Result>(),
+            Err(String::from("expected 'X', 'Y' or 'Z' for result, got A"))
+        );
+        assert_eq!(Result::Tie.to_string(), "Tie");
+    }
 }
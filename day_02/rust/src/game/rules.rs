@@ -0,0 +1,631 @@
+use super::{result::Result as GameResult, shape::Shape};
+use std::{error, fmt, sync::Arc, sync::OnceLock};
+
+const MAX_NUMBER_OF_SHAPES: usize = 26;
+
+static ROCK_PAPER_SCISSORS: OnceLock<GameRules> = OnceLock::new();
+
+#[derive(Debug, PartialEq)]
+pub struct RulesError {
+    cause: String,
+}
+
+impl RulesError {
+    pub fn build(cause: String) -> Self {
+        RulesError { cause }
+    }
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid game rules, {}", self.cause)
+    }
+}
+
+impl error::Error for RulesError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeRule {
+    name: String,
+    points: u32,
+}
+
+impl ShapeRule {
+    pub fn build(name: &str, points: u32) -> Self {
+        ShapeRule {
+            name: String::from(name),
+            points,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_points(&self) -> u32 {
+        self.points
+    }
+}
+
+// the rules are shared by every shape they declare, so they are cheap to clone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRules {
+    shapes: Arc<[ShapeRule]>,
+    beats: Arc<[Vec<bool>]>,
+}
+
+impl GameRules {
+    pub fn try_build(shapes: Vec<ShapeRule>, beats: &[(&str, &str)]) -> Result<Self, RulesError> {
+        if shapes.len() > MAX_NUMBER_OF_SHAPES {
+            return Err(RulesError::build(format!(
+                "expected at most {} shapes, got {}",
+                MAX_NUMBER_OF_SHAPES,
+                shapes.len()
+            )));
+        }
+
+        for (index, shape) in shapes.iter().enumerate() {
+            if shapes[..index]
+                .iter()
+                .any(|other_shape| other_shape.name == shape.name)
+            {
+                return Err(RulesError::build(format!(
+                    "shape '{}' is declared twice",
+                    shape.name
+                )));
+            }
+        }
+
+        let mut beats_table = vec![vec![false; shapes.len()]; shapes.len()];
+        for (winner, loser) in beats {
+            let winner_index = Self::try_get_declared_shape_index(&shapes, winner)?;
+            let loser_index = Self::try_get_declared_shape_index(&shapes, loser)?;
+
+            if winner_index == loser_index {
+                return Err(RulesError::build(format!(
+                    "shape '{}' cannot beat itself",
+                    winner
+                )));
+            }
+
+            beats_table[winner_index][loser_index] = true;
+        }
+
+        let rules = GameRules {
+            shapes: shapes.into(),
+            beats: beats_table.into(),
+        };
+
+        rules.try_validate_beats()?;
+
+        Ok(rules)
+    }
+
+    pub fn build_rock_paper_scissors() -> Self {
+        Self::try_build_cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    pub(crate) fn get_rock_paper_scissors() -> &'static GameRules {
+        ROCK_PAPER_SCISSORS.get_or_init(Self::build_rock_paper_scissors)
+    }
+
+    pub fn build_rock_paper_scissors_lizard_spock() -> Self {
+        let shapes = vec![
+            ShapeRule::build("Rock", 1),
+            ShapeRule::build("Paper", 2),
+            ShapeRule::build("Scissors", 3),
+            ShapeRule::build("Lizard", 4),
+            ShapeRule::build("Spock", 5),
+        ];
+
+        let beats = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+
+        Self::try_build(shapes, &beats).unwrap()
+    }
+
+    // every shape beats the half of the other shapes that come right before it in the cycle, so
+    // the number of shapes has to be odd for every pair of shapes to have a winner
+    pub fn try_build_cyclic(names: &[&str]) -> Result<Self, RulesError> {
+        if names.len() < 3 || names.len().is_multiple_of(2) {
+            return Err(RulesError::build(format!(
+                "expected an odd number of at least 3 shapes for a cyclic game, got {}",
+                names.len()
+            )));
+        }
+
+        let shapes = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| ShapeRule::build(name, index as u32 + 1))
+            .collect();
+
+        let mut beats = Vec::new();
+        for (index, winner) in names.iter().enumerate() {
+            for distance in 1..=names.len() / 2 {
+                let loser_index = (index + names.len() - distance) % names.len();
+                beats.push((*winner, names[loser_index]));
+            }
+        }
+
+        Self::try_build(shapes, &beats)
+    }
+
+    pub fn get_number_of_shapes(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn get_shapes(&self) -> &[ShapeRule] {
+        &self.shapes
+    }
+
+    pub fn get_all_shapes(&self) -> Vec<Shape> {
+        (0..self.shapes.len())
+            .map(|index| Shape::build(index, self))
+            .collect()
+    }
+
+    pub fn get_shape(&self, name: &str) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.name == name)
+            .map(|index| Shape::build(index, self))
+    }
+
+    pub fn get_shape_rule(&self, shape: &Shape) -> Option<&ShapeRule> {
+        if self.contains(shape) {
+            self.shapes.get(shape.get_index())
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, shape: &Shape) -> bool {
+        shape.get_rules() == self
+    }
+
+    pub fn try_beats(&self, shape: &Shape, other_shape: &Shape) -> Result<bool, RulesError> {
+        self.try_validate_shape(shape)?;
+        self.try_validate_shape(other_shape)?;
+
+        Ok(self.beats[shape.get_index()][other_shape.get_index()])
+    }
+
+    pub fn try_get_game_result(
+        &self,
+        opponent_shape: &Shape,
+        own_shape: &Shape,
+    ) -> Result<GameResult, RulesError> {
+        self.try_validate_shape(opponent_shape)?;
+        self.try_validate_shape(own_shape)?;
+
+        Ok(self.get_game_result_of_indices(opponent_shape.get_index(), own_shape.get_index()))
+    }
+
+    pub fn try_get_own_shape(
+        &self,
+        opponent_shape: &Shape,
+        result: &GameResult,
+    ) -> Result<Shape, RulesError> {
+        self.try_validate_shape(opponent_shape)?;
+
+        Ok(Shape::build(
+            self.get_own_shape_index(opponent_shape.get_index(), result),
+            self,
+        ))
+    }
+
+    // both indices have to be declared in the rules
+    pub(crate) fn get_game_result_of_indices(
+        &self,
+        opponent_index: usize,
+        own_index: usize,
+    ) -> GameResult {
+        if self.beats[own_index][opponent_index] {
+            GameResult::Win
+        } else if self.beats[opponent_index][own_index] {
+            GameResult::Loss
+        } else {
+            GameResult::Tie
+        }
+    }
+
+    // if several shapes lead to the result, the first declared one is played
+    pub(crate) fn get_own_shape_index(&self, opponent_index: usize, result: &GameResult) -> usize {
+        (0..self.shapes.len())
+            .find(|own_index| {
+                self.get_game_result_of_indices(opponent_index, *own_index) == *result
+            })
+            .unwrap_or(opponent_index)
+    }
+
+    // opponent shapes are lettered from 'A' and own shapes are lettered up to 'Z', in the order
+    // the shapes are declared
+    pub(crate) fn try_get_opponent_letter(&self, shape: &Shape) -> Result<char, RulesError> {
+        self.try_validate_shape(shape)?;
+
+        Ok(self.get_opponent_letters()[shape.get_index()])
+    }
+
+    pub(crate) fn try_get_own_letter(&self, shape: &Shape) -> Result<char, RulesError> {
+        self.try_validate_shape(shape)?;
+
+        Ok(self.get_own_letters()[shape.get_index()])
+    }
+
+    pub(crate) fn try_get_opponent_shape(&self, input: &str) -> Result<Shape, String> {
+        self.try_get_shape(input, &[&self.get_opponent_letters()])
+    }
+
+    // the letters of both columns are accepted, the opponent letters first if they overlap
+    pub(crate) fn try_get_any_column_shape(&self, input: &str) -> Result<Shape, String> {
+        self.try_get_shape(
+            input,
+            &[&self.get_opponent_letters(), &self.get_own_letters()],
+        )
+    }
+
+    // shapes of other rules may share names and indices with these rules, but not what beats them
+    fn try_validate_shape(&self, shape: &Shape) -> Result<(), RulesError> {
+        if self.contains(shape) {
+            Ok(())
+        } else {
+            Err(RulesError::build(format!(
+                "shape '{}' is not declared in these rules",
+                shape
+            )))
+        }
+    }
+
+    fn get_opponent_letters(&self) -> Vec<char> {
+        ('A'..='Z').take(self.shapes.len()).collect()
+    }
+
+    fn get_own_letters(&self) -> Vec<char> {
+        ('A'..='Z').skip(26 - self.shapes.len()).collect()
+    }
+
+    fn try_get_shape(&self, input: &str, letter_columns: &[&[char]]) -> Result<Shape, String> {
+        let mut chars = input.chars();
+
+        if let (Some(letter), None) = (chars.next(), chars.next()) {
+            for letters in letter_columns {
+                if let Some(index) = letters.iter().position(|l| *l == letter) {
+                    return Ok(Shape::build(index, self));
+                }
+            }
+        }
+
+        let mut letters: Vec<char> = Vec::new();
+        for letter in letter_columns.iter().flat_map(|letters| letters.iter()) {
+            if !letters.contains(letter) {
+                letters.push(*letter);
+            }
+        }
+
+        Err(format!(
+            "expected {} for shape, got {}",
+            format_letters(&letters),
+            input
+        ))
+    }
+
+    fn try_get_declared_shape_index(shapes: &[ShapeRule], name: &str) -> Result<usize, RulesError> {
+        match shapes.iter().position(|shape| shape.name == name) {
+            Some(index) => Ok(index),
+            None => Err(RulesError::build(format!(
+                "shape '{}' is not declared",
+                name
+            ))),
+        }
+    }
+
+    fn try_validate_beats(&self) -> Result<(), RulesError> {
+        for (index, shape) in self.shapes.iter().enumerate() {
+            for (other_index, other_shape) in self.shapes.iter().enumerate().skip(index + 1) {
+                match (
+                    self.beats[index][other_index],
+                    self.beats[other_index][index],
+                ) {
+                    (true, true) => {
+                        return Err(RulesError::build(format!(
+                            "shapes '{}' and '{}' beat each other",
+                            shape.name, other_shape.name
+                        )))
+                    }
+                    (false, false) => {
+                        return Err(RulesError::build(format!(
+                            "no winner between shapes '{}' and '{}'",
+                            shape.name, other_shape.name
+                        )))
+                    }
+                    _ => {}
+                }
+            }
+
+            if !(0..self.shapes.len()).any(|other_index| self.beats[index][other_index]) {
+                return Err(RulesError::build(format!(
+                    "shape '{}' does not beat any shape",
+                    shape.name
+                )));
+            }
+
+            if !(0..self.shapes.len()).any(|other_index| self.beats[other_index][index]) {
+                return Err(RulesError::build(format!(
+                    "shape '{}' is not beaten by any shape",
+                    shape.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// lists the letters like 'A', 'B' or 'C'
+pub(crate) fn format_letters(letters: &[char]) -> String {
+    let quoted_letters: Vec<String> = letters
+        .iter()
+        .map(|letter| format!("'{}'", letter))
+        .collect();
+
+    match quoted_letters.split_last() {
+        Some((last_letter, [])) => last_letter.clone(),
+        Some((last_letter, other_letters)) => {
+            format!("{} or {}", other_letters.join(", "), last_letter)
+        }
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{scoring::ScoringRules, Game};
+    use super::*;
+
+    #[test]
+    fn test_rock_paper_scissors_shapes() {
+        let rules = GameRules::build_rock_paper_scissors();
+
+        assert_eq!(rules.get_shape("Rock"), Some(Shape::ROCK));
+        assert_eq!(rules.get_shape("Paper"), Some(Shape::PAPER));
+        assert_eq!(rules.get_shape("Scissors"), Some(Shape::SCISSORS));
+        assert_eq!(rules.get_shape_rule(&Shape::ROCK).unwrap().get_points(), 1);
+        assert_eq!(rules.get_shape_rule(&Shape::PAPER).unwrap().get_points(), 2);
+        assert_eq!(
+            rules.get_shape_rule(&Shape::SCISSORS).unwrap().get_points(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_beats() {
+        let rules = GameRules::build_rock_paper_scissors();
+
+        for shape in Shape::all() {
+            let beaten_shape = rules.try_get_own_shape(&shape, &GameResult::Loss).unwrap();
+            let winning_shape = rules.try_get_own_shape(&shape, &GameResult::Win).unwrap();
+
+            assert_eq!(rules.try_beats(&shape, &beaten_shape), Ok(true));
+            assert_eq!(rules.try_beats(&winning_shape, &shape), Ok(true));
+            assert_eq!(rules.try_get_own_shape(&shape, &GameResult::Tie), Ok(shape));
+        }
+
+        assert_eq!(rules.try_beats(&Shape::ROCK, &Shape::SCISSORS), Ok(true));
+        assert_eq!(rules.try_beats(&Shape::PAPER, &Shape::ROCK), Ok(true));
+        assert_eq!(rules.try_beats(&Shape::ROCK, &Shape::PAPER), Ok(false));
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_matches_game() {
+        let rules = GameRules::build_rock_paper_scissors();
+        let scoring_rules = ScoringRules::try_build_for_rules(&rules).unwrap();
+
+        assert_eq!(scoring_rules, ScoringRules::default());
+        assert_eq!(rules.get_all_shapes(), Shape::all().to_vec());
+
+        for opponent_shape in rules.get_all_shapes() {
+            for own_shape in rules.get_all_shapes() {
+                assert_eq!(
+                    Game::try_build_v1(opponent_shape.clone(), own_shape.clone()),
+                    Game::try_build_v1(
+                        Shape::all()[opponent_shape.get_index()].clone(),
+                        Shape::all()[own_shape.get_index()].clone()
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock_results() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        let rock = rules.get_shape("Rock").unwrap();
+        let lizard = rules.get_shape("Lizard").unwrap();
+        let spock = rules.get_shape("Spock").unwrap();
+
+        assert_eq!(
+            rules.try_get_game_result(&rock, &lizard),
+            Ok(GameResult::Loss)
+        );
+        assert_eq!(
+            rules.try_get_game_result(&lizard, &spock),
+            Ok(GameResult::Loss)
+        );
+        assert_eq!(
+            rules.try_get_game_result(&rock, &spock),
+            Ok(GameResult::Win)
+        );
+        assert_eq!(
+            rules.try_get_game_result(&spock, &spock),
+            Ok(GameResult::Tie)
+        );
+    }
+
+    #[test]
+    fn test_shapes_of_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors();
+        let other_rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let spock = other_rules.get_shape("Spock").unwrap();
+        let error = RulesError::build(String::from("shape 'Spock' is not declared in these rules"));
+
+        assert!(!rules.contains(&spock));
+        assert!(!other_rules.contains(&Shape::ROCK));
+        assert_eq!(rules.get_shape_rule(&spock), None);
+        assert_eq!(rules.try_beats(&spock, &Shape::ROCK).unwrap_err(), error);
+        assert_eq!(rules.try_beats(&Shape::ROCK, &spock).unwrap_err(), error);
+        assert_eq!(
+            rules.try_get_game_result(&Shape::ROCK, &spock).unwrap_err(),
+            error
+        );
+        assert_eq!(
+            rules
+                .try_get_own_shape(&spock, &GameResult::Win)
+                .unwrap_err(),
+            error
+        );
+        assert_eq!(rules.try_get_own_letter(&spock).unwrap_err(), error);
+        assert_eq!(
+            Game::try_build_v1(Shape::ROCK, spock.clone()),
+            Err(RulesError::build(String::from(
+                "shape 'Spock' is not declared in these rules"
+            )))
+        );
+        assert_eq!(
+            Game::try_build_v1(spock, Shape::ROCK),
+            Err(RulesError::build(String::from(
+                "shape 'Rock' is not declared in these rules"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock_points() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let scoring_rules = ScoringRules::try_build_for_rules(&rules).unwrap();
+
+        let rock = rules.get_shape("Rock").unwrap();
+        let spock = rules.get_shape("Spock").unwrap();
+
+        assert_eq!(
+            Game::try_build_v1(rock.clone(), spock)
+                .unwrap()
+                .get_points(&scoring_rules),
+            11
+        );
+        assert_eq!(
+            Game::build_v2(rock.clone(), GameResult::Win).get_points(&scoring_rules),
+            8
+        );
+        assert_eq!(
+            Game::build_v2(rock, GameResult::Loss).get_points(&scoring_rules),
+            3
+        );
+    }
+
+    #[test]
+    fn test_shape_letters() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let rock = rules.get_shape("Rock").unwrap();
+        let spock = rules.get_shape("Spock").unwrap();
+
+        assert_eq!(rules.try_get_opponent_letter(&spock), Ok('E'));
+        assert_eq!(rules.try_get_own_letter(&spock), Ok('Z'));
+        assert_eq!(rules.try_get_own_letter(&rock), Ok('V'));
+        assert_eq!(rules.try_get_any_column_shape("Z"), Ok(spock));
+        assert_eq!(
+            rules.try_get_opponent_shape("Z"),
+            Err(String::from(
+                "expected 'A', 'B', 'C', 'D' or 'E' for shape, got Z"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cyclic_rules_are_balanced() {
+        let rules = GameRules::try_build_cyclic(&["A", "B", "C", "D", "E", "F", "G"]).unwrap();
+
+        for shape in rules.get_all_shapes() {
+            let number_of_beaten_shapes = rules
+                .get_all_shapes()
+                .iter()
+                .filter(|other_shape| rules.try_beats(&shape, other_shape) == Ok(true))
+                .count();
+            assert_eq!(number_of_beaten_shapes, 3);
+        }
+
+        assert_eq!(rules.get_shapes()[6].get_points(), 7);
+    }
+
+    #[test]
+    fn test_cyclic_rules_with_even_number_of_shapes() {
+        assert_eq!(
+            GameRules::try_build_cyclic(&["A", "B", "C", "D"]),
+            Err(RulesError::build(String::from(
+                "expected an odd number of at least 3 shapes for a cyclic game, got 4"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_rules_with_undeclared_shape() {
+        let shapes = vec![ShapeRule::build("Rock", 1)];
+
+        assert_eq!(
+            GameRules::try_build(shapes, &[("Rock", "Paper")]),
+            Err(RulesError::build(String::from(
+                "shape 'Paper' is not declared"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_rules_with_missing_winner() {
+        let shapes = vec![
+            ShapeRule::build("Rock", 1),
+            ShapeRule::build("Paper", 2),
+            ShapeRule::build("Scissors", 3),
+        ];
+
+        assert_eq!(
+            GameRules::try_build(shapes, &[("Paper", "Rock"), ("Scissors", "Paper")]),
+            Err(RulesError::build(String::from(
+                "no winner between shapes 'Rock' and 'Scissors'"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_rules_with_unbeaten_shape() {
+        let shapes = vec![
+            ShapeRule::build("Rock", 1),
+            ShapeRule::build("Paper", 2),
+            ShapeRule::build("Scissors", 3),
+        ];
+
+        assert_eq!(
+            GameRules::try_build(
+                shapes,
+                &[
+                    ("Paper", "Rock"),
+                    ("Paper", "Scissors"),
+                    ("Scissors", "Rock")
+                ]
+            ),
+            Err(RulesError::build(String::from(
+                "shape 'Rock' does not beat any shape"
+            )))
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use super::{result::Result as GameResult, rules::GameRules, shape::Shape};
+use serde::Deserialize;
+use std::{collections::BTreeMap, error, fmt, fs, path};
+
+#[derive(Debug, PartialEq)]
+pub struct ScoringRulesError {
+    cause: String,
+}
+
+impl ScoringRulesError {
+    pub fn build(cause: String) -> Self {
+        ScoringRulesError { cause }
+    }
+}
+
+impl fmt::Display for ScoringRulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not load scoring rules, {}", self.cause)
+    }
+}
+
+impl error::Error for ScoringRulesError {}
+
+// the points are keyed by shape name, ignoring case, the scoring files only need to name the
+// shapes whose points differ from the classic ones
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "BTreeMap<String, u32>")]
+struct ShapePoints {
+    points: Vec<(String, u32)>,
+}
+
+impl ShapePoints {
+    fn build(points: &[(&str, u32)]) -> Self {
+        ShapePoints {
+            points: points
+                .iter()
+                .map(|(name, points)| (name.to_ascii_lowercase(), *points))
+                .collect(),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<u32> {
+        self.points
+            .iter()
+            .find(|(shape_name, _)| shape_name.eq_ignore_ascii_case(name))
+            .map(|(_, points)| *points)
+    }
+
+    fn insert(&mut self, name: &str, points: u32) {
+        match self
+            .points
+            .iter_mut()
+            .find(|(shape_name, _)| shape_name.eq_ignore_ascii_case(name))
+        {
+            Some((_, shape_points)) => *shape_points = points,
+            None => self.points.push((name.to_ascii_lowercase(), points)),
+        }
+    }
+}
+
+impl From<BTreeMap<String, u32>> for ShapePoints {
+    fn from(points: BTreeMap<String, u32>) -> Self {
+        let mut shape_points = ShapePoints::default();
+        for (name, points) in points {
+            shape_points.insert(&name, points);
+        }
+
+        shape_points
+    }
+}
+
+impl Default for ShapePoints {
+    fn default() -> Self {
+        ShapePoints::build(&[("rock", 1), ("paper", 2), ("scissors", 3)])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ResultPoints {
+    loss: u32,
+    tie: u32,
+    win: u32,
+}
+
+impl Default for ResultPoints {
+    fn default() -> Self {
+        ResultPoints {
+            loss: GameResult::Loss.get_points(),
+            tie: GameResult::Tie.get_points(),
+            win: GameResult::Win.get_points(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScoringRules {
+    shapes: ShapePoints,
+    results: ResultPoints,
+}
+
+impl ScoringRules {
+    pub fn try_build(
+        shape_points: [u32; 3],
+        result_points: [u32; 3],
+    ) -> Result<Self, ScoringRulesError> {
+        ScoringRules {
+            shapes: ShapePoints::build(&[
+                ("rock", shape_points[0]),
+                ("paper", shape_points[1]),
+                ("scissors", shape_points[2]),
+            ]),
+            results: ResultPoints {
+                loss: result_points[0],
+                tie: result_points[1],
+                win: result_points[2],
+            },
+        }
+        .try_validate()
+    }
+
+    pub fn try_build_for_rules(rules: &GameRules) -> Result<Self, ScoringRulesError> {
+        ScoringRules {
+            shapes: ShapePoints::build(
+                &rules
+                    .get_shapes()
+                    .iter()
+                    .map(|shape| (shape.get_name(), shape.get_points()))
+                    .collect::<Vec<(&str, u32)>>(),
+            ),
+            results: ResultPoints::default(),
+        }
+        .try_validate()
+    }
+
+    pub fn try_load(file_name: &str) -> Result<Self, ScoringRulesError> {
+        let content = match fs::read_to_string(path::Path::new(file_name)) {
+            Ok(content) => content,
+            Err(err) => {
+                return Err(ScoringRulesError::build(format!(
+                    "could not read file '{}', {}",
+                    file_name, err
+                )))
+            }
+        };
+
+        let extension = path::Path::new(file_name)
+            .extension()
+            .and_then(|extension| extension.to_str());
+
+        match extension {
+            Some("toml") => Self::try_from_toml(&content),
+            Some("json") => Self::try_from_json(&content),
+            _ => Err(ScoringRulesError::build(format!(
+                "expected a '.toml' or '.json' file, got '{}'",
+                file_name
+            ))),
+        }
+    }
+
+    // every shape of the rules needs points, shapes of other rules may be named as well
+    pub fn try_load_for_rules(
+        file_name: &str,
+        rules: &GameRules,
+    ) -> Result<Self, ScoringRulesError> {
+        let scoring_rules = Self::try_load(file_name)?;
+        scoring_rules.try_validate_rules(rules)?;
+
+        Ok(scoring_rules)
+    }
+
+    pub fn try_validate_rules(&self, rules: &GameRules) -> Result<(), ScoringRulesError> {
+        for shape in rules.get_shapes() {
+            if self.shapes.get(shape.get_name()).is_none() {
+                return Err(ScoringRulesError::build(format!(
+                    "no points for shape '{}'",
+                    shape.get_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn try_from_toml(content: &str) -> Result<Self, ScoringRulesError> {
+        toml::from_str::<Self>(content)
+            .map_err(|err| ScoringRulesError::build(format!("invalid toml, {}", err)))?
+            .try_validate()
+    }
+
+    pub fn try_from_json(content: &str) -> Result<Self, ScoringRulesError> {
+        serde_json::from_str::<Self>(content)
+            .map_err(|err| ScoringRulesError::build(format!("invalid json, {}", err)))?
+            .try_validate()
+    }
+
+    // every game scores one shape and one result, so the highest of both must fit in its points
+    fn try_validate(self) -> Result<Self, ScoringRulesError> {
+        let max_shape_points = self
+            .shapes
+            .points
+            .iter()
+            .map(|(_, points)| *points)
+            .max()
+            .unwrap_or_default();
+        let max_result_points = self
+            .results
+            .loss
+            .max(self.results.tie)
+            .max(self.results.win);
+
+        match max_shape_points.checked_add(max_result_points) {
+            Some(_) => Ok(self),
+            None => Err(ScoringRulesError::build(format!(
+                "shape points {} and result points {} add up to more than {} points per game",
+                max_shape_points,
+                max_result_points,
+                u32::MAX
+            ))),
+        }
+    }
+
+    // shapes the scoring rules do not know score no points, try_validate_rules tells if the rules
+    // have any
+    pub fn get_shape_points(&self, shape: &Shape) -> u32 {
+        self.shapes.get(shape.get_name()).unwrap_or_default()
+    }
+
+    pub fn get_result_points(&self, result: &GameResult) -> u32 {
+        match result {
+            GameResult::Loss => self.results.loss,
+            GameResult::Tie => self.results.tie,
+            GameResult::Win => self.results.win,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_scoring_rules() {
+        let scoring_rules = ScoringRules::default();
+
+        assert_eq!(
+            scoring_rules,
+            ScoringRules::try_build([1, 2, 3], [0, 3, 6]).unwrap()
+        );
+        assert_eq!(scoring_rules.get_shape_points(&Shape::PAPER), 2);
+        assert_eq!(scoring_rules.get_result_points(&GameResult::Win), 6);
+    }
+
+    #[test]
+    fn test_toml_scoring_rules() {
+        let content = "[shapes]\nrock = 10\npaper = 20\nscissors = 30\n\n[results]\nwin = 100\n";
+
+        assert_eq!(
+            ScoringRules::try_from_toml(content),
+            ScoringRules::try_build([10, 20, 30], [0, 3, 100])
+        );
+    }
+
+    #[test]
+    fn test_json_scoring_rules() {
+        let content = r#"{"results": {"loss": 1, "tie": 2, "win": 3}}"#;
+
+        assert_eq!(
+            ScoringRules::try_from_json(content),
+            ScoringRules::try_build([1, 2, 3], [1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_empty_scoring_rules() {
+        assert_eq!(ScoringRules::try_from_toml(""), Ok(ScoringRules::default()));
+        assert_eq!(
+            ScoringRules::try_from_json("{}"),
+            Ok(ScoringRules::default())
+        );
+    }
+
+    #[test]
+    fn test_unknown_field_scoring_rules() {
+        let scoring_rules = ScoringRules::try_from_json(r#"{"results": {"draw": 4}}"#);
+
+        assert!(scoring_rules.is_err());
+    }
+
+    #[test]
+    fn test_scoring_rules_for_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let lizard = rules.get_shape("Lizard").unwrap();
+        let spock = rules.get_shape("Spock").unwrap();
+
+        let scoring_rules =
+            ScoringRules::try_from_toml("[shapes]\nRock = 10\nlizard = 40\nspock = 50\n").unwrap();
+
+        assert_eq!(scoring_rules.try_validate_rules(&rules), Ok(()));
+        assert_eq!(
+            scoring_rules.get_shape_points(&rules.get_shape("Rock").unwrap()),
+            10
+        );
+        assert_eq!(scoring_rules.get_shape_points(&Shape::ROCK), 10);
+        assert_eq!(scoring_rules.get_shape_points(&Shape::PAPER), 2);
+        assert_eq!(scoring_rules.get_shape_points(&lizard), 40);
+        assert_eq!(scoring_rules.get_shape_points(&spock), 50);
+    }
+
+    #[test]
+    fn test_scoring_rules_missing_shapes_of_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        assert_eq!(
+            ScoringRules::default().try_validate_rules(&rules),
+            Err(ScoringRulesError::build(String::from(
+                "no points for shape 'Lizard'"
+            )))
+        );
+        assert_eq!(
+            ScoringRules::try_load_for_rules("../input/test_scoring_rules.toml", &rules),
+            Err(ScoringRulesError::build(String::from(
+                "no points for shape 'Lizard'"
+            )))
+        );
+        assert_eq!(
+            ScoringRules::try_build_for_rules(&rules)
+                .unwrap()
+                .try_validate_rules(&rules),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_load_scoring_rules_files() {
+        assert_eq!(
+            ScoringRules::try_load("../input/test_scoring_rules.toml"),
+            ScoringRules::try_build([1, 2, 3], [0, 5, 10])
+        );
+        assert_eq!(
+            ScoringRules::try_load("../input/test_scoring_rules.json"),
+            ScoringRules::try_build([1, 2, 3], [0, 5, 10])
+        );
+    }
+
+    #[test]
+    fn test_overflowing_scoring_rules() {
+        let content = format!("[shapes]\nscissors = {}\n", u32::MAX - 5);
+
+        assert_eq!(
+            ScoringRules::try_from_toml(&content),
+            Err(ScoringRulesError::build(format!(
+                "shape points {} and result points 6 add up to more than {} points per game",
+                u32::MAX - 5,
+                u32::MAX
+            )))
+        );
+        assert!(ScoringRules::try_build([1, 2, 3], [0, u32::MAX - 3, 0]).is_ok());
+        assert!(ScoringRules::try_build([1, 2, 3], [0, u32::MAX - 2, 0]).is_err());
+    }
+
+    #[test]
+    fn test_load_scoring_rules_wrong_extension() {
+        let file_name = "../input/test_input.txt";
+
+        assert_eq!(
+            ScoringRules::try_load(file_name),
+            Err(ScoringRulesError::build(format!(
+                "expected a '.toml' or '.json' file, got '{}'",
+                file_name
+            )))
+        );
+    }
+}
@@ -1,39 +1,180 @@
-#[derive(Debug, PartialEq)]
-pub enum Shape {
-    Rock,
-    Paper,
-    Scissors,
+use super::{result::Result as GameResult, rules::GameRules};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+// a shape is the index of a shape declared in its game rules, the classic shapes play by the rock
+// paper scissors rules without holding them, shapes are not ordered since what beats what is cyclic
+#[derive(Clone)]
+pub struct Shape {
+    index: usize,
+    rules: Option<GameRules>,
 }
 
 impl Shape {
-    pub fn get_points(&self) -> u32 {
-        match self {
-            Shape::Rock => 1,
-            Shape::Paper => 2,
-            Shape::Scissors => 3,
+    pub const ROCK: Shape = Shape {
+        index: 0,
+        rules: None,
+    };
+    pub const PAPER: Shape = Shape {
+        index: 1,
+        rules: None,
+    };
+    pub const SCISSORS: Shape = Shape {
+        index: 2,
+        rules: None,
+    };
+
+    // the index has to be declared in the rules
+    pub(crate) fn build(index: usize, rules: &GameRules) -> Self {
+        Shape {
+            index,
+            rules: Some(rules.clone()),
+        }
+    }
+
+    pub fn all() -> [Shape; 3] {
+        [Shape::ROCK, Shape::PAPER, Shape::SCISSORS]
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_rules(&self) -> &GameRules {
+        match &self.rules {
+            Some(rules) => rules,
+            None => GameRules::get_rock_paper_scissors(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.get_rules().get_shapes()[self.index].get_name()
+    }
+
+    // with more than three shapes several shapes may be beaten, the first declared one is returned
+    pub fn beats(&self) -> Shape {
+        self.get_shape_for_result(&GameResult::Loss)
+    }
+
+    pub fn loses_to(&self) -> Shape {
+        self.get_shape_for_result(&GameResult::Win)
+    }
+
+    // the shape to play against this one for the result, in the rules of this shape
+    pub(crate) fn get_shape_for_result(&self, result: &GameResult) -> Shape {
+        Shape {
+            index: self.get_rules().get_own_shape_index(self.index, result),
+            rules: self.rules.clone(),
         }
     }
 }
 
+impl PartialEq for Shape {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+            && match (&self.rules, &other.rules) {
+                (None, None) => true,
+                _ => self.get_rules() == other.get_rules(),
+            }
+    }
+}
+
+impl Eq for Shape {}
+
+// equal shapes share their index, the rules are left out so that the hash stays cheap
+impl Hash for Shape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        GameRules::get_rock_paper_scissors().try_get_opponent_shape(input)
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl fmt::Debug for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_classic_shape_indices() {
+        assert_eq!(Shape::ROCK.get_index(), 0);
+        assert_eq!(Shape::PAPER.get_index(), 1);
+        assert_eq!(Shape::SCISSORS.get_index(), 2);
+    }
+
+    #[test]
+    fn test_beats_and_loses_to() {
+        for shape in Shape::all() {
+            assert_ne!(shape.beats(), shape);
+            assert_ne!(shape.loses_to(), shape);
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.loses_to().beats(), shape);
+        }
+
+        assert_eq!(Shape::ROCK.beats(), Shape::SCISSORS);
+        assert_eq!(Shape::ROCK.loses_to(), Shape::PAPER);
+    }
 
     #[test]
-    fn test_rock_points() {
-        let rock = Shape::Rock;
-        assert_eq!(rock.get_points(), 1);
+    fn test_beats_and_loses_to_with_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let spock = rules.get_shape("Spock").unwrap();
+
+        assert_eq!(spock.beats(), rules.get_shape("Rock").unwrap());
+        assert_eq!(spock.loses_to(), rules.get_shape("Paper").unwrap());
     }
 
     #[test]
-    fn test_paper_points() {
-        let paper = Shape::Paper;
-        assert_eq!(paper.get_points(), 2);
+    fn test_from_str_and_display() {
+        assert_eq!("B".parse::<Shape>(), Ok(Shape::PAPER));
+        assert_eq!(
+            "X".parse::<Shape>(),
+            Err(String::from("expected 'A', 'B' or 'C' for shape, got X"))
+        );
+        assert_eq!(Shape::SCISSORS.to_string(), "Scissors");
+    }
+
+    #[test]
+    fn test_display_with_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        assert_eq!(rules.get_shape("Spock").unwrap().to_string(), "Spock");
+        assert_eq!(rules.get_shape("Lizard").unwrap().to_string(), "Lizard");
+    }
+
+    #[test]
+    fn test_shapes_of_other_rules_are_different() {
+        let rules = GameRules::build_rock_paper_scissors();
+        let other_rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        assert_eq!(rules.get_shape("Rock"), Some(Shape::ROCK));
+        assert_ne!(other_rules.get_shape("Rock"), Some(Shape::ROCK));
     }
 
     #[test]
-    fn test_scissors_points() {
-        let scissors = Shape::Scissors;
-        assert_eq!(scissors.get_points(), 3);
+    fn test_shapes_as_keys() {
+        let shapes: HashSet<Shape> = Shape::all().into_iter().chain(Shape::all()).collect();
+        assert_eq!(shapes.len(), 3);
     }
 }
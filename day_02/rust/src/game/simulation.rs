@@ -0,0 +1,356 @@
+use super::{
+    result::Result as GameResult, rules::RulesError, scoring::ScoringRules, shape::Shape, Game,
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+
+fn get_most_frequent_shape(counts: &[usize; 3]) -> Option<Shape> {
+    let max_count = *counts.iter().max()?;
+
+    if max_count == 0 {
+        return None;
+    }
+
+    counts
+        .iter()
+        .position(|count| *count == max_count)
+        .map(|index| Shape::all()[index].clone())
+}
+
+// the counts are updated once per round so that the strategies never rescan the whole history
+#[derive(Debug, Default)]
+struct OpponentHistory {
+    last_shape: Option<Shape>,
+    shape_counts: [usize; 3],
+    transition_counts: [[usize; 3]; 3],
+}
+
+impl OpponentHistory {
+    fn push(&mut self, shape: Shape) {
+        self.shape_counts[shape.get_index()] += 1;
+        if let Some(last_shape) = &self.last_shape {
+            self.transition_counts[last_shape.get_index()][shape.get_index()] += 1;
+        }
+
+        self.last_shape = Some(shape);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Opponent {
+    Uniform,
+    FrequencyBiased { weights: [u32; 3] },
+    RepeatLast,
+}
+
+impl Opponent {
+    fn get_next_shape(&self, history: &OpponentHistory, random: &mut Pcg64Mcg) -> Shape {
+        match self {
+            Opponent::Uniform => Shape::all()[random.gen_range(0..3)].clone(),
+            Opponent::FrequencyBiased { weights } => Self::get_weighted_shape(weights, random),
+            Opponent::RepeatLast => match &history.last_shape {
+                Some(shape) => shape.clone(),
+                None => Shape::all()[random.gen_range(0..3)].clone(),
+            },
+        }
+    }
+
+    fn get_weighted_shape(weights: &[u32; 3], random: &mut Pcg64Mcg) -> Shape {
+        let total_weight: u64 = weights.iter().map(|weight| *weight as u64).sum();
+
+        if total_weight == 0 {
+            return Shape::all()[random.gen_range(0..3)].clone();
+        }
+
+        let mut target = random.gen_range(0..total_weight);
+        for (shape, weight) in Shape::all().iter().zip(weights) {
+            if target < *weight as u64 {
+                return shape.clone();
+            }
+            target -= *weight as u64;
+        }
+
+        Shape::SCISSORS
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Strategy {
+    FixedGuide { own_shapes: Vec<Shape> },
+    CounterMostFrequent,
+    Markov,
+}
+
+impl Strategy {
+    pub fn build_fixed_guide(guide_games: &[Game]) -> Self {
+        Strategy::FixedGuide {
+            own_shapes: guide_games
+                .iter()
+                .map(|game| game.get_own_shape())
+                .collect(),
+        }
+    }
+
+    fn get_next_shape(&self, round_index: usize, opponent_history: &OpponentHistory) -> Shape {
+        match self {
+            Strategy::FixedGuide { own_shapes } if !own_shapes.is_empty() => {
+                own_shapes[round_index % own_shapes.len()].clone()
+            }
+            Strategy::FixedGuide { .. } => Shape::ROCK,
+            Strategy::CounterMostFrequent => {
+                Self::get_counter_shape(Self::get_frequency_prediction(opponent_history))
+            }
+            Strategy::Markov => Self::get_counter_shape(
+                Self::get_markov_prediction(opponent_history)
+                    .or_else(|| Self::get_frequency_prediction(opponent_history)),
+            ),
+        }
+    }
+
+    fn get_counter_shape(predicted_shape: Option<Shape>) -> Shape {
+        match predicted_shape {
+            Some(shape) => shape.loses_to(),
+            None => Shape::ROCK,
+        }
+    }
+
+    fn get_frequency_prediction(opponent_history: &OpponentHistory) -> Option<Shape> {
+        get_most_frequent_shape(&opponent_history.shape_counts)
+    }
+
+    // predicts the shape that most often followed the opponent's last shape
+    fn get_markov_prediction(opponent_history: &OpponentHistory) -> Option<Shape> {
+        let last_shape = opponent_history.last_shape.as_ref()?;
+
+        get_most_frequent_shape(&opponent_history.transition_counts[last_shape.get_index()])
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SimulationResult {
+    number_of_rounds: usize,
+    total_points: u64,
+    number_of_wins: usize,
+    number_of_ties: usize,
+    number_of_losses: usize,
+}
+
+impl SimulationResult {
+    pub fn get_number_of_rounds(&self) -> usize {
+        self.number_of_rounds
+    }
+
+    pub fn get_total_points(&self) -> u64 {
+        self.total_points
+    }
+
+    pub fn get_number_of_wins(&self) -> usize {
+        self.number_of_wins
+    }
+
+    pub fn get_number_of_ties(&self) -> usize {
+        self.number_of_ties
+    }
+
+    pub fn get_number_of_losses(&self) -> usize {
+        self.number_of_losses
+    }
+
+    pub fn get_expected_score(&self) -> f64 {
+        if self.number_of_rounds == 0 {
+            return 0.0;
+        }
+
+        self.total_points as f64 / self.number_of_rounds as f64
+    }
+}
+
+pub struct Simulation {
+    seed: u64,
+    number_of_rounds: usize,
+    scoring_rules: ScoringRules,
+}
+
+impl Simulation {
+    pub fn build(seed: u64) -> Self {
+        Simulation {
+            seed,
+            number_of_rounds: 10000,
+            scoring_rules: ScoringRules::default(),
+        }
+    }
+
+    pub fn with_number_of_rounds(mut self, number_of_rounds: usize) -> Self {
+        self.number_of_rounds = number_of_rounds;
+        self
+    }
+
+    pub fn with_scoring_rules(mut self, scoring_rules: ScoringRules) -> Self {
+        self.scoring_rules = scoring_rules;
+        self
+    }
+
+    // the opponents play rock paper scissors, so a guide of other rules cannot be simulated
+    pub fn try_simulate(
+        &self,
+        opponent: &Opponent,
+        strategy: &Strategy,
+    ) -> Result<SimulationResult, RulesError> {
+        let mut random = Pcg64Mcg::seed_from_u64(self.seed);
+        let mut opponent_history = OpponentHistory::default();
+
+        let mut result = SimulationResult {
+            number_of_rounds: self.number_of_rounds,
+            total_points: 0,
+            number_of_wins: 0,
+            number_of_ties: 0,
+            number_of_losses: 0,
+        };
+
+        for round_index in 0..self.number_of_rounds {
+            let own_shape = strategy.get_next_shape(round_index, &opponent_history);
+            let opponent_shape = opponent.get_next_shape(&opponent_history, &mut random);
+
+            let game = Game::try_build_v1(opponent_shape.clone(), own_shape)?;
+
+            result.total_points += game.get_points(&self.scoring_rules) as u64;
+            match game.get_game_result() {
+                GameResult::Win => result.number_of_wins += 1,
+                GameResult::Tie => result.number_of_ties += 1,
+                GameResult::Loss => result.number_of_losses += 1,
+            }
+
+            opponent_history.push(opponent_shape);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::rules::GameRules;
+
+    #[test]
+    fn test_simulation_is_reproducible() {
+        let simulation = Simulation::build(7).with_number_of_rounds(500);
+
+        assert_eq!(
+            simulation
+                .try_simulate(&Opponent::Uniform, &Strategy::Markov)
+                .unwrap(),
+            simulation
+                .try_simulate(&Opponent::Uniform, &Strategy::Markov)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_empty_simulation() {
+        let result = Simulation::build(1)
+            .with_number_of_rounds(0)
+            .try_simulate(&Opponent::Uniform, &Strategy::CounterMostFrequent)
+            .unwrap();
+
+        assert_eq!(result.get_number_of_rounds(), 0);
+        assert_eq!(result.get_expected_score(), 0.0);
+    }
+
+    #[test]
+    fn test_uniform_opponent_against_fixed_guide() {
+        let strategy = Strategy::FixedGuide {
+            own_shapes: vec![Shape::PAPER],
+        };
+
+        let result = Simulation::build(3)
+            .with_number_of_rounds(30000)
+            .try_simulate(&Opponent::Uniform, &strategy)
+            .unwrap();
+
+        assert!((result.get_expected_score() - 5.0).abs() < 0.1);
+        assert_eq!(
+            result.get_number_of_wins()
+                + result.get_number_of_ties()
+                + result.get_number_of_losses(),
+            30000
+        );
+    }
+
+    #[test]
+    fn test_counter_most_frequent_beats_biased_opponent() {
+        let opponent = Opponent::FrequencyBiased { weights: [8, 1, 1] };
+
+        let result = Simulation::build(11)
+            .with_number_of_rounds(10000)
+            .try_simulate(&opponent, &Strategy::CounterMostFrequent)
+            .unwrap();
+
+        assert!(result.get_number_of_wins() > 7500);
+    }
+
+    #[test]
+    fn test_markov_beats_repeat_last_opponent() {
+        let result = Simulation::build(5)
+            .with_number_of_rounds(1000)
+            .try_simulate(&Opponent::RepeatLast, &Strategy::Markov)
+            .unwrap();
+
+        assert!(result.get_number_of_wins() >= 999);
+    }
+
+    #[test]
+    fn test_fixed_guide_of_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let strategy = Strategy::FixedGuide {
+            own_shapes: vec![rules.get_shape("Spock").unwrap()],
+        };
+
+        assert_eq!(
+            Simulation::build(1).try_simulate(&Opponent::Uniform, &strategy),
+            Err(RulesError::build(String::from(
+                "shape 'Spock' is not declared in these rules"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_fixed_guide_strategy_is_cyclic() {
+        let strategy = Strategy::build_fixed_guide(&[
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::build_v2(Shape::ROCK, GameResult::Loss),
+        ]);
+
+        let history = OpponentHistory::default();
+
+        assert_eq!(strategy.get_next_shape(0, &history), Shape::PAPER);
+        assert_eq!(strategy.get_next_shape(1, &history), Shape::SCISSORS);
+        assert_eq!(strategy.get_next_shape(2, &history), Shape::PAPER);
+    }
+
+    #[test]
+    fn test_markov_prediction() {
+        let mut history = OpponentHistory::default();
+        assert_eq!(Strategy::get_markov_prediction(&history), None);
+
+        for shape in [
+            Shape::ROCK,
+            Shape::PAPER,
+            Shape::ROCK,
+            Shape::PAPER,
+            Shape::SCISSORS,
+            Shape::ROCK,
+        ] {
+            history.push(shape);
+        }
+
+        assert_eq!(
+            Strategy::get_markov_prediction(&history),
+            Some(Shape::PAPER)
+        );
+        assert_eq!(
+            Strategy::get_frequency_prediction(&history),
+            Some(Shape::ROCK)
+        );
+    }
+}
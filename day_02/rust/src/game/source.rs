@@ -0,0 +1,20 @@
+use super::{parser::ParsingError, Game};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ParsingMode {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+pub trait GameSource {
+    fn try_get_games_by_line(&mut self) -> Result<Vec<(usize, Game)>, ParsingError>;
+
+    fn try_get_games(&mut self) -> Result<Vec<Game>, ParsingError> {
+        let games = self.try_get_games_by_line()?;
+
+        Ok(games.into_iter().map(|(_, game)| game).collect())
+    }
+
+    fn get_diagnostics(&self) -> &[ParsingError];
+}
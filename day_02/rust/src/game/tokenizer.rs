@@ -0,0 +1,105 @@
+const COMMENT_MARKER: char = '#';
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Token<'a> {
+    input: &'a str,
+    column_index: usize,
+}
+
+impl<'a> Token<'a> {
+    pub fn get_input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn get_column_index(&self) -> usize {
+        self.column_index
+    }
+}
+
+pub fn get_tokens(line: &str) -> Vec<Token<'_>> {
+    let content = match line.split_once(COMMENT_MARKER) {
+        Some((content, _)) => content,
+        None => line,
+    };
+
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+
+    for (column_index, (byte_index, character)) in content.char_indices().enumerate() {
+        match (token_start, character.is_whitespace()) {
+            (None, false) => token_start = Some((column_index + 1, byte_index)),
+            (Some((token_column_index, token_byte_index)), true) => {
+                tokens.push(Token {
+                    input: &content[token_byte_index..byte_index],
+                    column_index: token_column_index,
+                });
+                token_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((token_column_index, token_byte_index)) = token_start {
+        tokens.push(Token {
+            input: &content[token_byte_index..],
+            column_index: token_column_index,
+        });
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_inputs_and_column_indices(line: &str) -> Vec<(&str, usize)> {
+        get_tokens(line)
+            .iter()
+            .map(|token| (token.get_input(), token.get_column_index()))
+            .collect()
+    }
+
+    #[test]
+    fn test_single_spaces() {
+        assert_eq!(
+            get_inputs_and_column_indices("A Y"),
+            vec![("A", 1), ("Y", 3)]
+        );
+    }
+
+    #[test]
+    fn test_tabs_and_repeated_spaces() {
+        assert_eq!(
+            get_inputs_and_column_indices("  B\t  Z \r"),
+            vec![("B", 3), ("Z", 7)]
+        );
+    }
+
+    #[test]
+    fn test_blank_and_comment_lines() {
+        assert_eq!(get_tokens(""), vec![]);
+        assert_eq!(get_tokens(" \t "), vec![]);
+        assert_eq!(get_tokens("# A Y"), vec![]);
+    }
+
+    #[test]
+    fn test_trailing_comment() {
+        assert_eq!(
+            get_inputs_and_column_indices("C X # always rock#"),
+            vec![("C", 1), ("X", 3)]
+        );
+        assert_eq!(
+            get_inputs_and_column_indices("C X#Y"),
+            vec![("C", 1), ("X", 3)]
+        );
+    }
+
+    #[test]
+    fn test_columns_count_characters() {
+        assert_eq!(
+            get_inputs_and_column_indices("ä B Ö"),
+            vec![("ä", 1), ("B", 3), ("Ö", 5)]
+        );
+    }
+}
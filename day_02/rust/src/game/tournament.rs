@@ -0,0 +1,460 @@
+pub mod parser;
+
+use super::{result::Result as GameResult, rules::GameRules, scoring::ScoringRules, shape::Shape};
+use std::{cmp::Ordering, error, fmt, str::FromStr};
+
+#[derive(Debug, PartialEq)]
+pub struct TournamentError {
+    cause: String,
+}
+
+impl TournamentError {
+    pub fn build(cause: String) -> Self {
+        TournamentError { cause }
+    }
+}
+
+impl fmt::Display for TournamentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid tournament, {}", self.cause)
+    }
+}
+
+impl error::Error for TournamentError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TieBreak {
+    Wins,
+    FewestLosses,
+    HeadToHead,
+    Name,
+}
+
+impl FromStr for TieBreak {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "wins" => Ok(TieBreak::Wins),
+            "fewest-losses" => Ok(TieBreak::FewestLosses),
+            "head-to-head" => Ok(TieBreak::HeadToHead),
+            "name" => Ok(TieBreak::Name),
+            input => Err(format!(
+                "expected 'wins', 'fewest-losses', 'head-to-head' or 'name' for tie break, got {}",
+                input
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Standing {
+    player: String,
+    points: u64,
+    number_of_wins: usize,
+    number_of_ties: usize,
+    number_of_losses: usize,
+}
+
+impl Standing {
+    pub fn get_player(&self) -> &str {
+        &self.player
+    }
+
+    pub fn get_points(&self) -> u64 {
+        self.points
+    }
+
+    pub fn get_number_of_wins(&self) -> usize {
+        self.number_of_wins
+    }
+
+    pub fn get_number_of_ties(&self) -> usize {
+        self.number_of_ties
+    }
+
+    pub fn get_number_of_losses(&self) -> usize {
+        self.number_of_losses
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Tournament {
+    players: Vec<String>,
+    rounds: Vec<Vec<Shape>>,
+    rules: GameRules,
+}
+
+impl Tournament {
+    pub fn try_build(
+        players: Vec<String>,
+        rounds: Vec<Vec<Shape>>,
+    ) -> Result<Self, TournamentError> {
+        Self::try_build_with_rules(players, rounds, GameRules::build_rock_paper_scissors())
+    }
+
+    pub fn try_build_with_rules(
+        players: Vec<String>,
+        rounds: Vec<Vec<Shape>>,
+        rules: GameRules,
+    ) -> Result<Self, TournamentError> {
+        if players.len() < 2 {
+            return Err(TournamentError::build(format!(
+                "expected at least 2 players, got {}",
+                players.len()
+            )));
+        }
+
+        for (index, player) in players.iter().enumerate() {
+            if players[..index].contains(player) {
+                return Err(TournamentError::build(format!(
+                    "player '{}' is declared twice",
+                    player
+                )));
+            }
+        }
+
+        for (round_index, round) in rounds.iter().enumerate() {
+            if round.len() != players.len() {
+                return Err(TournamentError::build(format!(
+                    "expected {} shapes in round {}, got {}",
+                    players.len(),
+                    round_index + 1,
+                    round.len()
+                )));
+            }
+
+            if let Some(shape) = round.iter().find(|shape| !rules.contains(shape)) {
+                return Err(TournamentError::build(format!(
+                    "shape '{}' in round {} is not declared in the rules",
+                    shape,
+                    round_index + 1
+                )));
+            }
+        }
+
+        Ok(Tournament {
+            players,
+            rounds,
+            rules,
+        })
+    }
+
+    pub fn get_players(&self) -> &[String] {
+        &self.players
+    }
+
+    pub fn get_number_of_rounds(&self) -> usize {
+        self.rounds.len()
+    }
+
+    pub fn get_standings(
+        &self,
+        scoring_rules: &ScoringRules,
+        tie_breaks: &[TieBreak],
+    ) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = self
+            .players
+            .iter()
+            .map(|player| Standing {
+                player: player.clone(),
+                points: 0,
+                number_of_wins: 0,
+                number_of_ties: 0,
+                number_of_losses: 0,
+            })
+            .collect();
+
+        // the points each player scored against each other player over all rounds
+        let mut head_to_head_points = vec![vec![0; self.players.len()]; self.players.len()];
+
+        for round in self.rounds.iter() {
+            for (player_index, own_shape) in round.iter().enumerate() {
+                for (opponent_index, opponent_shape) in round.iter().enumerate() {
+                    if player_index == opponent_index {
+                        continue;
+                    }
+
+                    // every shape was checked to be declared in the rules when building
+                    let result = self.rules.get_game_result_of_indices(
+                        opponent_shape.get_index(),
+                        own_shape.get_index(),
+                    );
+                    let points = u64::from(scoring_rules.get_shape_points(own_shape))
+                        + u64::from(scoring_rules.get_result_points(&result));
+                    let standing = &mut standings[player_index];
+
+                    standing.points += points;
+                    head_to_head_points[player_index][opponent_index] += points;
+                    match result {
+                        GameResult::Win => standing.number_of_wins += 1,
+                        GameResult::Tie => standing.number_of_ties += 1,
+                        GameResult::Loss => standing.number_of_losses += 1,
+                    }
+                }
+            }
+        }
+
+        let mut tied_groups = Self::split_into_tied_groups(
+            (0..self.players.len()).collect(),
+            |index, other_index| {
+                standings[*other_index]
+                    .points
+                    .cmp(&standings[*index].points)
+            },
+        );
+
+        for tie_break in tie_breaks {
+            tied_groups = tied_groups
+                .into_iter()
+                .flat_map(|tied_group| {
+                    let compare = Self::get_tie_break_comparison(
+                        *tie_break,
+                        &standings,
+                        &head_to_head_points,
+                        &tied_group,
+                    );
+                    Self::split_into_tied_groups(tied_group.clone(), compare)
+                })
+                .collect();
+        }
+
+        let mut standings: Vec<Option<Standing>> = standings.into_iter().map(Some).collect();
+        tied_groups
+            .into_iter()
+            .flatten()
+            .filter_map(|index| standings[index].take())
+            .collect()
+    }
+
+    // sorts the players stably and groups the ones that are still tied
+    fn split_into_tied_groups<F>(mut player_indices: Vec<usize>, compare: F) -> Vec<Vec<usize>>
+    where
+        F: Fn(&usize, &usize) -> Ordering,
+    {
+        player_indices.sort_by(&compare);
+
+        let mut tied_groups: Vec<Vec<usize>> = Vec::new();
+        for index in player_indices {
+            match tied_groups.last_mut() {
+                Some(tied_group) if compare(&tied_group[0], &index) == Ordering::Equal => {
+                    tied_group.push(index)
+                }
+                _ => tied_groups.push(vec![index]),
+            }
+        }
+
+        tied_groups
+    }
+
+    // head to head only counts the points scored against the other players of the tied group
+    fn get_tie_break_comparison<'a>(
+        tie_break: TieBreak,
+        standings: &'a [Standing],
+        head_to_head_points: &'a [Vec<u64>],
+        tied_group: &'a [usize],
+    ) -> impl Fn(&usize, &usize) -> Ordering + 'a {
+        let get_group_points = move |index: usize| -> u64 {
+            tied_group
+                .iter()
+                .map(|other_index| head_to_head_points[index][*other_index])
+                .sum()
+        };
+
+        move |index, other_index| {
+            let (standing, other_standing) = (&standings[*index], &standings[*other_index]);
+
+            match tie_break {
+                TieBreak::Wins => other_standing.number_of_wins.cmp(&standing.number_of_wins),
+                TieBreak::FewestLosses => standing
+                    .number_of_losses
+                    .cmp(&other_standing.number_of_losses),
+                TieBreak::HeadToHead => {
+                    get_group_points(*other_index).cmp(&get_group_points(*index))
+                }
+                TieBreak::Name => standing.player.cmp(&other_standing.player),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_tournament(players: &[&str], rounds: &[&[Shape]]) -> Tournament {
+        Tournament::try_build(
+            players.iter().map(|player| player.to_string()).collect(),
+            rounds.iter().map(|round| round.to_vec()).collect(),
+        )
+        .unwrap()
+    }
+
+    fn get_players(standings: &[Standing]) -> Vec<&str> {
+        standings
+            .iter()
+            .map(|standing| standing.get_player())
+            .collect()
+    }
+
+    #[test]
+    fn test_invalid_tournaments() {
+        assert_eq!(
+            Tournament::try_build(vec![String::from("alice")], vec![]),
+            Err(TournamentError::build(String::from(
+                "expected at least 2 players, got 1"
+            )))
+        );
+        assert_eq!(
+            Tournament::try_build(vec![String::from("alice"), String::from("alice")], vec![]),
+            Err(TournamentError::build(String::from(
+                "player 'alice' is declared twice"
+            )))
+        );
+        assert_eq!(
+            Tournament::try_build(
+                vec![String::from("alice"), String::from("bob")],
+                vec![vec![Shape::ROCK]]
+            ),
+            Err(TournamentError::build(String::from(
+                "expected 2 shapes in round 1, got 1"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_tournament_with_shapes_of_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let players = vec![String::from("alice"), String::from("bob")];
+        let spock = rules.get_shape("Spock").unwrap();
+
+        assert_eq!(
+            Tournament::try_build(players.clone(), vec![vec![Shape::ROCK, spock.clone()]]),
+            Err(TournamentError::build(String::from(
+                "shape 'Spock' in round 1 is not declared in the rules"
+            )))
+        );
+        assert_eq!(
+            Tournament::try_build_with_rules(
+                players.clone(),
+                vec![vec![Shape::ROCK, spock.clone()]],
+                rules.clone()
+            ),
+            Err(TournamentError::build(String::from(
+                "shape 'Rock' in round 1 is not declared in the rules"
+            )))
+        );
+
+        let tournament = Tournament::try_build_with_rules(
+            players,
+            vec![vec![rules.get_shape("Rock").unwrap(), spock]],
+            rules,
+        )
+        .unwrap();
+        let standings = tournament.get_standings(&ScoringRules::default(), &[]);
+
+        assert_eq!(get_players(&standings), vec!["bob", "alice"]);
+        assert_eq!(standings[0].get_number_of_wins(), 1);
+    }
+
+    #[test]
+    fn test_two_players_match_games() {
+        let tournament = build_tournament(
+            &["alice", "bob"],
+            &[
+                &[Shape::ROCK, Shape::PAPER],
+                &[Shape::SCISSORS, Shape::PAPER],
+            ],
+        );
+
+        let standings = tournament.get_standings(&ScoringRules::default(), &[]);
+
+        assert_eq!(get_players(&standings), vec!["alice", "bob"]);
+        assert_eq!(standings[0].get_points(), 1 + 9);
+        assert_eq!(standings[1].get_points(), 8 + 2);
+        assert_eq!(standings[0].get_number_of_wins(), 1);
+        assert_eq!(standings[0].get_number_of_losses(), 1);
+    }
+
+    #[test]
+    fn test_points_beyond_u32() {
+        let scoring_rules = ScoringRules::try_build([0, 0, 0], [0, 0, u32::MAX]).unwrap();
+
+        let tournament = build_tournament(
+            &["alice", "bob"],
+            &[&[Shape::PAPER, Shape::ROCK], &[Shape::PAPER, Shape::ROCK]],
+        );
+
+        let standings = tournament.get_standings(&scoring_rules, &[TieBreak::HeadToHead]);
+
+        assert_eq!(get_players(&standings), vec!["alice", "bob"]);
+        assert_eq!(standings[0].get_points(), 2 * u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn test_tie_breaks() {
+        let scoring_rules = ScoringRules::try_build([0, 0, 0], [0, 1, 2]).unwrap();
+
+        // everyone ends on 4 points, bob with two wins and two losses, dave and carol with a win,
+        // two ties and a loss
+        let tournament = build_tournament(
+            &["dave", "bob", "carol"],
+            &[
+                &[Shape::ROCK, Shape::PAPER, Shape::ROCK],
+                &[Shape::PAPER, Shape::ROCK, Shape::PAPER],
+            ],
+        );
+
+        let standings = tournament.get_standings(&scoring_rules, &[]);
+        assert_eq!(
+            standings
+                .iter()
+                .map(|standing| standing.get_points())
+                .collect::<Vec<u64>>(),
+            vec![4, 4, 4]
+        );
+        assert_eq!(get_players(&standings), vec!["dave", "bob", "carol"]);
+
+        let standings = tournament.get_standings(&scoring_rules, &[TieBreak::Name]);
+        assert_eq!(get_players(&standings), vec!["bob", "carol", "dave"]);
+
+        let standings =
+            tournament.get_standings(&scoring_rules, &[TieBreak::FewestLosses, TieBreak::Name]);
+        assert_eq!(get_players(&standings), vec!["carol", "dave", "bob"]);
+
+        let standings = tournament.get_standings(&scoring_rules, &[TieBreak::Wins, TieBreak::Name]);
+        assert_eq!(get_players(&standings), vec!["bob", "carol", "dave"]);
+
+        let standings = tournament.get_standings(&scoring_rules, &[TieBreak::Wins]);
+        assert_eq!(get_players(&standings), vec!["bob", "dave", "carol"]);
+    }
+
+    #[test]
+    fn test_head_to_head_tie_break() {
+        let scoring_rules = ScoringRules::try_build([0, 0, 0], [0, 1, 2]).unwrap();
+
+        // alice and bob both end on 5 points, but bob scored 3 of them against alice and alice
+        // only 1 against bob
+        let tournament = build_tournament(
+            &["alice", "bob", "carol"],
+            &[
+                &[Shape::SCISSORS, Shape::ROCK, Shape::PAPER],
+                &[Shape::ROCK, Shape::ROCK, Shape::SCISSORS],
+            ],
+        );
+
+        let standings = tournament.get_standings(&scoring_rules, &[]);
+        assert_eq!(get_players(&standings), vec!["alice", "bob", "carol"]);
+
+        let standings = tournament.get_standings(&scoring_rules, &[TieBreak::HeadToHead]);
+        assert_eq!(get_players(&standings), vec!["bob", "alice", "carol"]);
+        assert_eq!(standings[0].get_points(), 5);
+        assert_eq!(standings[1].get_points(), 5);
+    }
+
+    #[test]
+    fn test_tie_break_from_str() {
+        assert_eq!("head-to-head".parse::<TieBreak>(), Ok(TieBreak::HeadToHead));
+        assert!("random".parse::<TieBreak>().is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use super::{
+    super::{
+        parser::{self, ParsingError},
+        rules::GameRules,
+        shape::Shape,
+        tokenizer,
+    },
+    Tournament,
+};
+use std::io::BufRead;
+
+pub struct TournamentParser {
+    file_name: String,
+    current_line: String,
+    current_line_index: usize,
+    rules: GameRules,
+}
+
+impl TournamentParser {
+    pub fn build(file_name: &str) -> Self {
+        TournamentParser {
+            file_name: String::from(file_name),
+            current_line: String::new(),
+            current_line_index: 0,
+            rules: GameRules::build_rock_paper_scissors(),
+        }
+    }
+
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    // the first line names the players, every following line holds one shape per player
+    pub fn try_get_tournament(&mut self) -> Result<Tournament, ParsingError> {
+        let file = parser::try_open_file(&self.file_name)?;
+        let mut players: Option<Vec<String>> = None;
+        let mut rounds = Vec::new();
+
+        self.current_line_index = 0;
+
+        for line in file.lines() {
+            self.current_line_index += 1;
+            self.current_line =
+                parser::try_get_line(&self.file_name, self.current_line_index, line)?;
+
+            match &players {
+                None => players = self.get_players(),
+                Some(players) => {
+                    if let Some(round) = self.try_get_round(players.len())? {
+                        rounds.push(round);
+                    }
+                }
+            }
+        }
+
+        let players = match players {
+            Some(players) => players,
+            None => {
+                return Err(ParsingError::build(format!(
+                    "no players declared in file '{}'",
+                    self.file_name
+                )))
+            }
+        };
+
+        Tournament::try_build_with_rules(players, rounds, self.rules.clone()).map_err(|err| {
+            ParsingError::build(format!("error in file '{}', {}", self.file_name, err))
+        })
+    }
+
+    fn get_players(&self) -> Option<Vec<String>> {
+        let players: Vec<String> = tokenizer::get_tokens(&self.current_line)
+            .iter()
+            .map(|token| token.get_input().to_string())
+            .collect();
+
+        if players.is_empty() {
+            None
+        } else {
+            Some(players)
+        }
+    }
+
+    fn try_get_round(&self, number_of_players: usize) -> Result<Option<Vec<Shape>>, ParsingError> {
+        let tokens = tokenizer::get_tokens(&self.current_line);
+
+        if tokens.is_empty() {
+            return Ok(None);
+        }
+
+        if tokens.len() != number_of_players {
+            let cause = format!(
+                "expected {} shapes, got {}",
+                number_of_players,
+                tokens.len()
+            );
+
+            return Err(match tokens.get(number_of_players) {
+                Some(unexpected_input) => ParsingError::build_column_error(
+                    &self.file_name,
+                    self.current_line_index,
+                    unexpected_input.get_column_index(),
+                    cause,
+                ),
+                None => {
+                    ParsingError::build_line_error(&self.file_name, self.current_line_index, cause)
+                }
+            });
+        }
+
+        tokens
+            .iter()
+            .map(|token| {
+                self.rules
+                    .try_get_opponent_shape(token.get_input())
+                    .map_err(|cause| {
+                        ParsingError::build_column_error(
+                            &self.file_name,
+                            self.current_line_index,
+                            token.get_column_index(),
+                            cause,
+                        )
+                    })
+            })
+            .collect::<Result<Vec<Shape>, ParsingError>>()
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::scoring::ScoringRules;
+
+    #[test]
+    fn test_structure_tournament() {
+        let file_name = "../input/test_input_tournament.txt";
+
+        let tournament = TournamentParser::build(file_name).try_get_tournament();
+
+        let expected_tournament = Tournament::try_build(
+            vec![
+                String::from("alice"),
+                String::from("bob"),
+                String::from("carol"),
+            ],
+            vec![
+                vec![Shape::ROCK, Shape::PAPER, Shape::SCISSORS],
+                vec![Shape::SCISSORS, Shape::SCISSORS, Shape::ROCK],
+                vec![Shape::PAPER, Shape::ROCK, Shape::ROCK],
+            ],
+        );
+
+        assert_eq!(tournament, Ok(expected_tournament.unwrap()));
+    }
+
+    #[test]
+    fn test_tournament_with_other_rules() {
+        let file_name = "../input/test_input_tournament_rock_paper_scissors_lizard_spock.txt";
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        let tournament = TournamentParser::build(file_name)
+            .with_rules(rules.clone())
+            .try_get_tournament()
+            .unwrap();
+        let standings =
+            tournament.get_standings(&ScoringRules::try_build_for_rules(&rules).unwrap(), &[]);
+
+        assert_eq!(tournament.get_number_of_rounds(), 2);
+        assert_eq!(standings[0].get_player(), "alice");
+        assert_eq!(standings[0].get_points(), 5 + 6 + 4 + 6);
+        assert_eq!(standings[1].get_points(), 1 + 2);
+
+        assert_eq!(
+            TournamentParser::build(file_name).try_get_tournament(),
+            Err(ParsingError::build_column_error(
+                file_name,
+                4,
+                1,
+                String::from("expected 'A', 'B' or 'C' for shape, got E")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_shape_tournament() {
+        let file_name = "../input/test_input.txt";
+
+        let tournament = TournamentParser::build(file_name).try_get_tournament();
+
+        assert_eq!(
+            tournament,
+            Err(ParsingError::build_column_error(
+                file_name,
+                2,
+                3,
+                String::from("expected 'A', 'B' or 'C' for shape, got X")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_missing_shape_tournament() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let tournament = TournamentParser::build(file_name).try_get_tournament();
+
+        assert_eq!(
+            tournament,
+            Err(ParsingError::build_line_error(
+                file_name,
+                2,
+                String::from("expected 2 shapes, got 1")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_missing_input_file_tournament() {
+        let file_name = "../input/wrong_test_input.txt";
+
+        let tournament = TournamentParser::build(file_name).try_get_tournament();
+
+        assert_eq!(
+            tournament,
+            Err(ParsingError::build(format!(
+                "could not open file '{}', No such file or directory (os error 2)",
+                file_name,
+            )))
+        );
+    }
+}
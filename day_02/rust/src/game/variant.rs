@@ -1,4 +1,262 @@
+use super::{
+    optimizer::Mapping, result::Result as GameResult, rules::GameRules, shape::Shape, Game,
+};
+use std::{fmt, rc::Rc};
+
+// what a letter of the second column stands for, the own shape or the result of the game
+#[derive(Debug, Clone, PartialEq)]
+pub enum SecondColumn {
+    Shape(Shape),
+    Result(GameResult),
+}
+
+// reads the second column of a guide line, for interpretations the built-in variants do not cover
+pub trait Interpretation {
+    fn try_interpret(&self, rules: &GameRules, input: &str) -> Result<SecondColumn, String>;
+}
+
+impl<F> Interpretation for F
+where
+    F: Fn(&GameRules, &str) -> Result<SecondColumn, String>,
+{
+    fn try_interpret(&self, rules: &GameRules, input: &str) -> Result<SecondColumn, String> {
+        self(rules, input)
+    }
+}
+
+#[derive(Clone)]
 pub enum Variant {
-    V1,
-    V2,
+    Shape,
+    Outcome,
+    Mapping(Mapping),
+    Custom(Rc<dyn Interpretation>),
+}
+
+impl Variant {
+    pub fn build_custom<I: Interpretation + 'static>(interpretation: I) -> Self {
+        Variant::Custom(Rc::new(interpretation))
+    }
+
+    pub(crate) fn try_get_opponent_shape(
+        &self,
+        rules: &GameRules,
+        input: &str,
+    ) -> Result<Shape, String> {
+        match self {
+            // the shape variant has always accepted the letters of both columns for either shape
+            Variant::Shape => rules.try_get_any_column_shape(input),
+            Variant::Outcome | Variant::Mapping(_) | Variant::Custom(_) => {
+                rules.try_get_opponent_shape(input)
+            }
+        }
+    }
+
+    pub(crate) fn try_get_game(
+        &self,
+        rules: &GameRules,
+        opponent_shape: Shape,
+        input: &str,
+    ) -> Result<Game, String> {
+        let game = match self.try_get_second_column(rules, input)? {
+            SecondColumn::Shape(own_shape) => {
+                Game::try_build_v1(opponent_shape, own_shape).map_err(|err| err.to_string())?
+            }
+            SecondColumn::Result(result) => Game::build_v2(opponent_shape, result),
+        };
+
+        Ok(game)
+    }
+
+    fn try_get_second_column(
+        &self,
+        rules: &GameRules,
+        input: &str,
+    ) -> Result<SecondColumn, String> {
+        let second_column = match self {
+            Variant::Shape => SecondColumn::Shape(rules.try_get_any_column_shape(input)?),
+            Variant::Outcome => SecondColumn::Result(input.parse::<GameResult>()?),
+            Variant::Mapping(Mapping::Shapes(shapes)) => {
+                SecondColumn::Shape(Self::try_get_own_shape(input, shapes)?)
+            }
+            Variant::Mapping(Mapping::Results(results)) => {
+                SecondColumn::Result(Self::try_get_result(input, results)?)
+            }
+            Variant::Custom(interpretation) => interpretation.try_interpret(rules, input)?,
+        };
+
+        Ok(second_column)
+    }
+
+    fn try_get_own_shape(input: &str, shapes: &[Shape; 3]) -> Result<Shape, String> {
+        match Self::get_column_index(input) {
+            Some(index) => Ok(shapes[index].clone()),
+            None => Err(format!("expected 'X', 'Y' or 'Z' for shape, got {}", input)),
+        }
+    }
+
+    fn try_get_result(input: &str, results: &[GameResult; 3]) -> Result<GameResult, String> {
+        match Self::get_column_index(input) {
+            Some(index) => Ok(results[index]),
+            None => Err(format!(
+                "expected 'X', 'Y' or 'Z' for result, got {}",
+                input
+            )),
+        }
+    }
+
+    fn get_column_index(input: &str) -> Option<usize> {
+        match input {
+            "X" => Some(0),
+            "Y" => Some(1),
+            "Z" => Some(2),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Variant::Shape => write!(f, "Shape"),
+            Variant::Outcome => write!(f, "Outcome"),
+            Variant::Mapping(mapping) => write!(f, "Mapping({:?})", mapping),
+            Variant::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_rules() -> GameRules {
+        GameRules::build_rock_paper_scissors()
+    }
+
+    #[test]
+    fn test_shape_variant() {
+        let rules = get_rules();
+
+        assert_eq!(
+            Variant::Shape.try_get_opponent_shape(&rules, "X"),
+            Ok(Shape::ROCK)
+        );
+        assert_eq!(
+            Variant::Shape.try_get_game(&rules, Shape::ROCK, "C"),
+            Ok(Game::try_build_v1(Shape::ROCK, Shape::SCISSORS).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_outcome_variant() {
+        let rules = get_rules();
+
+        assert_eq!(
+            Variant::Outcome.try_get_game(&rules, Shape::PAPER, "Z"),
+            Ok(Game::build_v2(Shape::PAPER, GameResult::Win))
+        );
+        assert_eq!(
+            Variant::Outcome.try_get_opponent_shape(&rules, "X"),
+            Err(String::from("expected 'A', 'B' or 'C' for shape, got X"))
+        );
+    }
+
+    #[test]
+    fn test_shape_mapping_variant() {
+        let rules = get_rules();
+
+        let variant = Variant::Mapping(Mapping::Shapes([
+            Shape::SCISSORS,
+            Shape::ROCK,
+            Shape::PAPER,
+        ]));
+
+        assert_eq!(
+            variant.try_get_game(&rules, Shape::ROCK, "X"),
+            Ok(Game::try_build_v1(Shape::ROCK, Shape::SCISSORS).unwrap())
+        );
+        assert_eq!(
+            variant.try_get_game(&rules, Shape::ROCK, "C"),
+            Err(String::from("expected 'X', 'Y' or 'Z' for shape, got C"))
+        );
+    }
+
+    #[test]
+    fn test_result_mapping_variant() {
+        let rules = get_rules();
+
+        let variant = Variant::Mapping(Mapping::Results([
+            GameResult::Win,
+            GameResult::Loss,
+            GameResult::Tie,
+        ]));
+
+        assert_eq!(
+            variant.try_get_game(&rules, Shape::SCISSORS, "Y"),
+            Ok(Game::build_v2(Shape::SCISSORS, GameResult::Loss))
+        );
+        assert_eq!(
+            variant.try_get_game(&rules, Shape::SCISSORS, "W"),
+            Err(String::from("expected 'X', 'Y' or 'Z' for result, got W"))
+        );
+    }
+
+    #[test]
+    fn test_shape_variant_with_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let lizard = rules.get_shape("Lizard").unwrap();
+        let spock = rules.get_shape("Spock").unwrap();
+
+        assert_eq!(
+            Variant::Shape.try_get_opponent_shape(&rules, "D"),
+            Ok(lizard.clone())
+        );
+        assert_eq!(
+            Variant::Shape.try_get_game(&rules, lizard.clone(), "Z"),
+            Ok(Game::try_build_v1(lizard.clone(), spock).unwrap())
+        );
+        assert_eq!(
+            Variant::Shape.try_get_game(&rules, lizard.clone(), "F"),
+            Err(String::from(
+                "expected 'A', 'B', 'C', 'D', 'E', 'V', 'W', 'X', 'Y' or 'Z' for shape, got F"
+            ))
+        );
+
+        let variant = Variant::Mapping(Mapping::Shapes(Shape::all()));
+        assert_eq!(
+            variant.try_get_game(&rules, lizard, "Z"),
+            Err(String::from(
+                "invalid game rules, shape 'Scissors' is not declared in these rules"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_custom_variant() {
+        let rules = get_rules();
+
+        // L, D and W for a lost, drawn and won game
+        let variant = Variant::build_custom(|_: &GameRules, input: &str| match input {
+            "L" => Ok(SecondColumn::Result(GameResult::Loss)),
+            "D" => Ok(SecondColumn::Result(GameResult::Tie)),
+            "W" => Ok(SecondColumn::Result(GameResult::Win)),
+            _ => Err(format!(
+                "expected 'L', 'D' or 'W' for result, got {}",
+                input
+            )),
+        });
+
+        assert_eq!(
+            variant.try_get_opponent_shape(&rules, "B"),
+            Ok(Shape::PAPER)
+        );
+        assert_eq!(
+            variant.try_get_game(&rules, Shape::PAPER, "W"),
+            Ok(Game::build_v2(Shape::PAPER, GameResult::Win))
+        );
+        assert_eq!(
+            variant.try_get_game(&rules, Shape::PAPER, "X"),
+            Err(String::from("expected 'L', 'D' or 'W' for result, got X"))
+        );
+    }
 }
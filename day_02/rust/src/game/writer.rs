@@ -0,0 +1,206 @@
+use super::{
+    result::Result as GameResult,
+    rules::{GameRules, RulesError},
+    Game,
+};
+use std::{
+    fs,
+    io::{self, Write},
+    path,
+};
+
+pub struct GuideWriter<W: Write> {
+    writer: io::BufWriter<W>,
+    rules: GameRules,
+}
+
+impl GuideWriter<fs::File> {
+    pub fn try_build_file(file_name: &str) -> Result<Self, io::Error> {
+        let file = fs::File::create(path::Path::new(file_name))?;
+        Ok(GuideWriter::build(file))
+    }
+}
+
+impl<W: Write> GuideWriter<W> {
+    pub fn build(writer: W) -> Self {
+        GuideWriter {
+            writer: io::BufWriter::new(writer),
+            rules: GameRules::build_rock_paper_scissors(),
+        }
+    }
+
+    pub fn with_rules(mut self, rules: GameRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    pub fn try_write_games(&mut self, games: &[Game]) -> Result<(), io::Error> {
+        for game in games {
+            let guide_line = self
+                .try_get_guide_line(game)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+            writeln!(self.writer, "{}", guide_line)?;
+        }
+
+        self.writer.flush()
+    }
+
+    fn try_get_guide_line(&self, game: &Game) -> Result<String, RulesError> {
+        let rules = &self.rules;
+
+        let guide_line = match game {
+            Game::V1 {
+                opponent_shape,
+                own_shape,
+                ..
+            } => format!(
+                "{} {}",
+                rules.try_get_opponent_letter(opponent_shape)?,
+                rules.try_get_own_letter(own_shape)?
+            ),
+            Game::V2 {
+                opponent_shape,
+                result,
+                ..
+            } => format!(
+                "{} {}",
+                rules.try_get_opponent_letter(opponent_shape)?,
+                Self::get_result_letter(result)
+            ),
+        };
+
+        Ok(guide_line)
+    }
+
+    fn get_result_letter(result: &GameResult) -> char {
+        match result {
+            GameResult::Loss => 'X',
+            GameResult::Tie => 'Y',
+            GameResult::Win => 'Z',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{parser::Parser, shape::Shape, source::GameSource};
+    use std::{env, process};
+
+    // the process id keeps concurrent test runs from sharing a file
+    fn get_temporary_file_name(test_name: &str) -> String {
+        env::temp_dir()
+            .join(format!(
+                "advent_of_code_2022_day_02_{}_{}.txt",
+                process::id(),
+                test_name
+            ))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[test]
+    fn test_write_games() {
+        let games = vec![
+            Game::try_build_v1(Shape::ROCK, Shape::PAPER).unwrap(),
+            Game::build_v2(Shape::SCISSORS, GameResult::Loss),
+            Game::build_v2(Shape::PAPER, GameResult::Win),
+        ];
+
+        let mut output = Vec::new();
+        GuideWriter::build(&mut output)
+            .try_write_games(&games)
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "A Y\nC X\nB Z\n");
+    }
+
+    #[test]
+    fn test_write_games_with_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let mut parser =
+            Parser::build_v1("../input/test_input_rock_paper_scissors_lizard_spock.txt")
+                .with_rules(rules.clone());
+        let games = parser.try_get_games().unwrap();
+
+        let mut output = Vec::new();
+        GuideWriter::build(&mut output)
+            .with_rules(rules)
+            .try_write_games(&games)
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "A Z\nD W\nE X\nB W\n");
+    }
+
+    #[test]
+    fn test_write_games_of_other_rules() {
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+        let mut parser =
+            Parser::build_v1("../input/test_input_rock_paper_scissors_lizard_spock.txt")
+                .with_rules(rules);
+        let games = parser.try_get_games().unwrap();
+
+        let mut output = Vec::new();
+        let err = GuideWriter::build(&mut output)
+            .try_write_games(&games)
+            .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "invalid game rules, shape 'Rock' is not declared in these rules"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_v1_to_v2() {
+        let mut parser = Parser::build_v1("../input/test_input.txt");
+        let games = parser.try_get_games().unwrap();
+
+        let converted_games: Vec<Game> = games.iter().map(|game| game.to_v2()).collect();
+
+        let file_name = get_temporary_file_name("test_round_trip_v1_to_v2");
+        GuideWriter::try_build_file(&file_name)
+            .unwrap()
+            .try_write_games(&converted_games)
+            .unwrap();
+
+        let parsed_games = Parser::build_v2(&file_name).try_get_games().unwrap();
+        fs::remove_file(&file_name).unwrap();
+
+        assert_eq!(parsed_games, converted_games);
+        assert_eq!(
+            parsed_games
+                .iter()
+                .map(|game| game.to_v1())
+                .collect::<Vec<Game>>(),
+            games
+        );
+    }
+
+    #[test]
+    fn test_round_trip_v2_to_v1() {
+        let mut parser = Parser::build_v2("../input/test_input.txt");
+        let games = parser.try_get_games().unwrap();
+
+        let converted_games: Vec<Game> = games.iter().map(|game| game.to_v1()).collect();
+
+        let file_name = get_temporary_file_name("test_round_trip_v2_to_v1");
+        GuideWriter::try_build_file(&file_name)
+            .unwrap()
+            .try_write_games(&converted_games)
+            .unwrap();
+
+        let parsed_games = Parser::build_v1(&file_name).try_get_games().unwrap();
+        fs::remove_file(&file_name).unwrap();
+
+        assert_eq!(parsed_games, converted_games);
+        assert_eq!(
+            parsed_games
+                .iter()
+                .map(|game| game.to_v2())
+                .collect::<Vec<Game>>(),
+            games
+        );
+    }
+}
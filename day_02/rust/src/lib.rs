@@ -1,47 +1,110 @@
 mod game;
 
-use game::{parser::Parser, parser_functional, Game};
+pub use game::{
+    optimizer::{Mapping, MappingScore, MappingScores},
+    parser::{Parser, ParsingError},
+    parser_functional::FunctionalParser,
+    report::{GamesReport, ReportFormat, Round},
+    result::Result as GameResult,
+    rules::{GameRules, RulesError, ShapeRule},
+    scoring::{ScoringRules, ScoringRulesError},
+    shape::Shape,
+    simulation::{Opponent, Simulation, SimulationResult, Strategy},
+    source::{GameSource, ParsingMode},
+    tournament::{parser::TournamentParser, Standing, TieBreak, Tournament, TournamentError},
+    variant::{Interpretation, SecondColumn, Variant},
+    writer::GuideWriter,
+};
 
-pub fn get_game_variant_1_points(file_name: &str) -> u32 {
-    let mut game_variant_1_parser = Parser::build_v1(file_name);
-    let games = match game_variant_1_parser.try_get_games() {
-        Ok(games) => games,
-        Err(_) => return 0,
-    };
+use game::Game;
 
-    get_games_points(games)
+pub fn get_game_variant_1_points(file_name: &str) -> u64 {
+    get_games_points_from_source(&mut Parser::build_v1(file_name), &ScoringRules::default())
+        .unwrap_or(0)
 }
 
-pub fn get_game_variant_1_points_functional(file_name: &str) -> u32 {
-    let games = match parser_functional::try_get_games_variant_1(file_name) {
-        Some(games) => games,
-        None => return 0,
-    };
+pub fn get_game_variant_1_points_functional(file_name: &str) -> u64 {
+    get_games_points_from_source(
+        &mut FunctionalParser::build_v1(file_name),
+        &ScoringRules::default(),
+    )
+    .unwrap_or(0)
+}
+
+pub fn get_game_variant_2_points(file_name: &str) -> u64 {
+    get_games_points_from_source(&mut Parser::build_v2(file_name), &ScoringRules::default())
+        .unwrap_or(0)
+}
+
+pub fn get_game_variant_2_points_functional(file_name: &str) -> u64 {
+    get_games_points_from_source(
+        &mut FunctionalParser::build_v2(file_name),
+        &ScoringRules::default(),
+    )
+    .unwrap_or(0)
+}
+
+pub fn get_games_points_from_source<S: GameSource>(
+    source: &mut S,
+    scoring_rules: &ScoringRules,
+) -> Result<u64, ParsingError> {
+    Ok(get_games_points(source.try_get_games()?, scoring_rules))
+}
+
+pub fn get_games_report_from_source<S: GameSource>(
+    source: &mut S,
+    scoring_rules: &ScoringRules,
+) -> Result<GamesReport, ParsingError> {
+    Ok(GamesReport::build(
+        &source.try_get_games_by_line()?,
+        scoring_rules,
+    ))
+}
 
-    get_games_points(games)
+pub fn get_tournament_standings(
+    file_name: &str,
+    scoring_rules: &ScoringRules,
+    tie_breaks: &[TieBreak],
+) -> Result<Vec<Standing>, ParsingError> {
+    let tournament = TournamentParser::build(file_name).try_get_tournament()?;
+
+    Ok(tournament.get_standings(scoring_rules, tie_breaks))
 }
 
-pub fn get_game_variant_2_points(file_name: &str) -> u32 {
-    let mut game_variant_2_parser = Parser::build_v2(file_name);
-    let games = match game_variant_2_parser.try_get_games() {
-        Ok(games) => games,
-        Err(_) => return 0,
-    };
+pub fn get_shape_mapping_scores(file_name: &str) -> Result<MappingScores, ParsingError> {
+    get_mapping_scores(file_name, Mapping::get_all_shape_mappings())
+}
 
-    get_games_points(games)
+pub fn get_result_mapping_scores(file_name: &str) -> Result<MappingScores, ParsingError> {
+    get_mapping_scores(file_name, Mapping::get_all_result_mappings())
 }
 
-pub fn get_game_variant_2_points_functional(file_name: &str) -> u32 {
-    let games = match parser_functional::try_get_games_variant_2(file_name) {
-        Some(games) => games,
-        None => return 0,
-    };
+pub fn get_guide_strategy(file_name: &str) -> Result<Strategy, ParsingError> {
+    let mut guide_parser = Parser::build_v1(file_name);
+    let guide_games = guide_parser.try_get_games()?;
 
-    get_games_points(games)
+    Ok(Strategy::build_fixed_guide(&guide_games))
 }
 
-fn get_games_points(games: Vec<Game>) -> u32 {
-    games.iter().map(|game| game.get_points()).sum()
+fn get_mapping_scores(
+    file_name: &str,
+    mappings: Vec<Mapping>,
+) -> Result<MappingScores, ParsingError> {
+    let mut guide_parser = Parser::build_v1(file_name);
+    let guide_games = guide_parser.try_get_games()?;
+
+    Ok(MappingScores::build(
+        &guide_games,
+        mappings,
+        &ScoringRules::default(),
+    ))
+}
+
+fn get_games_points(games: Vec<Game>, scoring_rules: &ScoringRules) -> u64 {
+    games
+        .iter()
+        .map(|game| u64::from(game.get_points(scoring_rules)))
+        .sum()
 }
 
 #[cfg(test)]
@@ -71,4 +134,224 @@ mod tests {
         let file_name = "../input/test_input.txt";
         assert_eq!(get_game_variant_2_points_functional(file_name), 12);
     }
+
+    #[test]
+    fn test_result_invalid_input_game_variant_1() {
+        let file_name = "../input/test_input_invalid.txt";
+        assert_eq!(get_game_variant_1_points(file_name), 0);
+        assert_eq!(get_game_variant_1_points_functional(file_name), 0);
+    }
+
+    #[test]
+    fn test_result_invalid_input_lenient_game_variant_1() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let mut parser = Parser::build_v1(file_name).with_mode(ParsingMode::Lenient);
+
+        assert_eq!(
+            get_games_points_from_source(&mut parser, &ScoringRules::default()),
+            Ok(14)
+        );
+        assert_eq!(parser.get_diagnostics().len(), 2);
+    }
+
+    #[test]
+    fn test_result_custom_scoring_rules_game_variant_2() {
+        let file_name = "../input/test_input.txt";
+        let scoring_rules = ScoringRules::try_load("../input/test_scoring_rules.toml").unwrap();
+
+        assert_eq!(
+            get_games_points_from_source(&mut Parser::build_v2(file_name), &scoring_rules),
+            Ok(18)
+        );
+    }
+
+    #[test]
+    fn test_report_lenient_game_variant_1() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        let mut parser = Parser::build_v1(file_name).with_mode(ParsingMode::Lenient);
+        let report = get_games_report_from_source(&mut parser, &ScoringRules::default()).unwrap();
+
+        let line_indices: Vec<usize> = report
+            .get_rounds()
+            .iter()
+            .map(|round| round.get_line_index())
+            .collect();
+
+        assert_eq!(line_indices, vec![1, 3]);
+        assert_eq!(report.get_total_points(), 14);
+    }
+
+    #[test]
+    fn test_result_mapping_variant() {
+        let file_name = "../input/test_input.txt";
+
+        for mapping in Mapping::get_all_result_mappings() {
+            let expected_points = MappingScores::build(
+                &Parser::build_v1(file_name).try_get_games().unwrap(),
+                vec![mapping.clone()],
+                &ScoringRules::default(),
+            )
+            .get_scores()[0]
+                .get_points();
+
+            assert_eq!(
+                get_games_points_from_source(
+                    &mut Parser::build(file_name, Variant::Mapping(mapping)),
+                    &ScoringRules::default()
+                ),
+                Ok(expected_points)
+            );
+        }
+    }
+
+    #[test]
+    fn test_result_rock_paper_scissors_rules_game_variant_1() {
+        let file_name = "../input/test_input.txt";
+        let rules = GameRules::build_rock_paper_scissors();
+
+        assert_eq!(
+            get_games_points_from_source(
+                &mut Parser::build_v1(file_name).with_rules(rules.clone()),
+                &ScoringRules::try_build_for_rules(&rules).unwrap()
+            ),
+            Ok(15)
+        );
+    }
+
+    #[test]
+    fn test_result_rock_paper_scissors_rules_game_variant_2() {
+        let file_name = "../input/test_input.txt";
+        let rules = GameRules::build_rock_paper_scissors();
+
+        assert_eq!(
+            get_games_points_from_source(
+                &mut Parser::build_v2(file_name).with_rules(rules.clone()),
+                &ScoringRules::try_build_for_rules(&rules).unwrap()
+            ),
+            Ok(12)
+        );
+    }
+
+    #[test]
+    fn test_result_rock_paper_scissors_lizard_spock_rules_game_variant_1() {
+        let file_name = "../input/test_input_rock_paper_scissors_lizard_spock.txt";
+        let rules = GameRules::build_rock_paper_scissors_lizard_spock();
+
+        assert_eq!(
+            get_games_points_from_source(
+                &mut Parser::build_v1(file_name).with_rules(rules.clone()),
+                &ScoringRules::try_build_for_rules(&rules).unwrap()
+            ),
+            Ok(21)
+        );
+    }
+
+    #[test]
+    fn test_result_custom_variant() {
+        let file_name = "../input/test_input.txt";
+
+        // reads X, Y and Z like the outcome variant
+        let variant = Variant::build_custom(|_: &GameRules, input: &str| {
+            input.parse::<GameResult>().map(SecondColumn::Result)
+        });
+
+        assert_eq!(
+            get_games_points_from_source(
+                &mut Parser::build(file_name, variant),
+                &ScoringRules::default()
+            ),
+            Ok(12)
+        );
+    }
+
+    #[test]
+    fn test_tournament_standings() {
+        let file_name = "../input/test_input_tournament.txt";
+        let standings =
+            get_tournament_standings(file_name, &ScoringRules::default(), &[TieBreak::Wins])
+                .unwrap();
+
+        let players_and_points: Vec<(&str, u64)> = standings
+            .iter()
+            .map(|standing| (standing.get_player(), standing.get_points()))
+            .collect();
+
+        assert_eq!(
+            players_and_points,
+            vec![("alice", 33), ("carol", 31), ("bob", 24)]
+        );
+        assert_eq!(standings[2].get_number_of_wins(), 1);
+        assert_eq!(standings[2].get_number_of_ties(), 2);
+        assert_eq!(standings[2].get_number_of_losses(), 3);
+    }
+
+    #[test]
+    fn test_shape_mapping_scores() {
+        let file_name = "../input/test_input.txt";
+        let scores = get_shape_mapping_scores(file_name).unwrap();
+
+        assert_eq!(scores.get_scores()[0].get_points(), 15);
+        assert_eq!(scores.get_best().unwrap().get_points(), 24);
+        assert_eq!(scores.get_worst().unwrap().get_points(), 6);
+    }
+
+    #[test]
+    fn test_result_mapping_scores() {
+        let file_name = "../input/test_input.txt";
+        let scores = get_result_mapping_scores(file_name).unwrap();
+
+        assert_eq!(scores.get_scores()[0].get_points(), 12);
+        assert_eq!(scores.get_best().unwrap().get_points(), 18);
+        assert_eq!(scores.get_worst().unwrap().get_points(), 12);
+    }
+
+    #[test]
+    fn test_guide_strategy_simulation() {
+        let file_name = "../input/test_input.txt";
+        let strategy = get_guide_strategy(file_name).unwrap();
+
+        let result = Simulation::build(0)
+            .with_number_of_rounds(3000)
+            .try_simulate(&Opponent::RepeatLast, &strategy)
+            .unwrap();
+
+        assert_eq!(result.get_number_of_rounds(), 3000);
+    }
+
+    #[test]
+    fn test_guide_strategy_missing_input_file() {
+        let file_name = "../input/wrong_test_input.txt";
+
+        assert_eq!(
+            get_guide_strategy(file_name),
+            Err(ParsingError::build(format!(
+                "could not open file '{}', No such file or directory (os error 2)",
+                file_name,
+            )))
+        );
+    }
+
+    #[test]
+    fn test_mapping_scores_missing_input_file() {
+        let file_name = "../input/wrong_test_input.txt";
+        let get_error = || {
+            ParsingError::build(format!(
+                "could not open file '{}', No such file or directory (os error 2)",
+                file_name,
+            ))
+        };
+
+        assert_eq!(get_shape_mapping_scores(file_name), Err(get_error()));
+        assert_eq!(get_result_mapping_scores(file_name), Err(get_error()));
+    }
+
+    #[test]
+    fn test_mapping_scores_invalid_input_file() {
+        let file_name = "../input/test_input_invalid.txt";
+
+        assert!(get_shape_mapping_scores(file_name).is_err());
+        assert!(get_guide_strategy(file_name).is_err());
+    }
 }
@@ -1,7 +1,11 @@
 use advent_of_code_2022_day_02::{
     get_game_variant_1_points, get_game_variant_1_points_functional, get_game_variant_2_points,
-    get_game_variant_2_points_functional,
+    get_game_variant_2_points_functional, get_games_points_from_source,
+    get_games_report_from_source, get_guide_strategy, get_result_mapping_scores,
+    get_shape_mapping_scores, get_tournament_standings, GameSource, GuideWriter, MappingScores,
+    Opponent, Parser, ParsingError, ReportFormat, ScoringRules, Simulation, Strategy, TieBreak,
 };
+use std::env;
 
 fn main() {
     let file_name = "../input/input.txt";
@@ -25,4 +29,263 @@ fn main() {
         "Games variant 2 points (functional): {}",
         get_game_variant_2_points_functional(file_name)
     );
+
+    print_mapping_scores("Shape mappings", get_shape_mapping_scores(file_name));
+
+    print_mapping_scores("Result mappings", get_result_mapping_scores(file_name));
+
+    let mut scoring_file_name = None;
+    let mut report_format = None;
+    let mut report_variant = 1;
+    let mut number_of_simulated_rounds = None;
+    let mut conversions = Vec::new();
+    let mut tournament_file_name = None;
+    let mut tie_breaks = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--scoring", Some(file_name)) => scoring_file_name = Some(file_name),
+            ("--report", Some(format)) => match format.parse::<ReportFormat>() {
+                Ok(format) => report_format = Some(format),
+                Err(err) => eprintln!("Invalid report format, {}", err),
+            },
+            ("--variant", Some(variant)) => match variant.parse::<u32>() {
+                Ok(variant @ 1..=2) => report_variant = variant,
+                _ => eprintln!("Invalid variant, expected 1 or 2, got {}", variant),
+            },
+            ("--simulate", Some(rounds)) => match rounds.parse::<usize>() {
+                Ok(rounds) => number_of_simulated_rounds = Some(rounds),
+                Err(err) => eprintln!("Invalid number of rounds, {}", err),
+            },
+            ("--to-v1", Some(output_file_name)) => conversions.push((1, output_file_name)),
+            ("--to-v2", Some(output_file_name)) => conversions.push((2, output_file_name)),
+            ("--tournament", Some(file_name)) => tournament_file_name = Some(file_name),
+            ("--tie-breaks", Some(rules)) => {
+                match rules
+                    .split(',')
+                    .map(|rule| rule.parse::<TieBreak>())
+                    .collect::<Result<Vec<TieBreak>, String>>()
+                {
+                    Ok(rules) => tie_breaks = rules,
+                    Err(err) => eprintln!("Invalid tie breaks, {}", err),
+                }
+            }
+            _ => eprintln!(
+                "Expected '--scoring <file>', '--report <format>', '--variant <1|2>', '--simulate <rounds>', '--to-v1 <file>', '--to-v2 <file>', '--tournament <file>' or '--tie-breaks <rules>', got '{}'",
+                arg
+            ),
+        }
+    }
+
+    let scoring_rules = match &scoring_file_name {
+        Some(scoring_file_name) => match ScoringRules::try_load(scoring_file_name) {
+            Ok(scoring_rules) => {
+                print_scoring_rules_points(file_name, scoring_file_name, &scoring_rules);
+                scoring_rules
+            }
+            Err(err) => {
+                eprintln!("Error while loading scoring rules, {}", err);
+                return;
+            }
+        },
+        None => ScoringRules::default(),
+    };
+
+    if let Some(report_format) = report_format {
+        print_games_report(file_name, report_variant, report_format, &scoring_rules);
+    }
+
+    for (target_variant, output_file_name) in conversions {
+        convert_guide(file_name, target_variant, &output_file_name);
+    }
+
+    if let Some(tournament_file_name) = tournament_file_name {
+        print_tournament_standings(&tournament_file_name, &scoring_rules, &tie_breaks);
+    }
+
+    if let Some(number_of_simulated_rounds) = number_of_simulated_rounds {
+        let simulation = Simulation::build(0)
+            .with_number_of_rounds(number_of_simulated_rounds)
+            .with_scoring_rules(scoring_rules);
+
+        print_simulation(file_name, &simulation);
+    }
+}
+
+fn print_mapping_scores(title: &str, scores: Result<MappingScores, ParsingError>) {
+    let scores = match scores {
+        Ok(scores) => scores,
+        Err(err) => {
+            eprintln!("Error while getting {}, {}", title.to_lowercase(), err);
+            return;
+        }
+    };
+
+    println!("{}:", title);
+
+    for score in scores.get_scores() {
+        println!("  {}: {} points", score.get_mapping(), score.get_points());
+    }
+
+    if let (Some(best), Some(worst)) = (scores.get_best(), scores.get_worst()) {
+        println!(
+            "  best: {} with {} points",
+            best.get_mapping(),
+            best.get_points()
+        );
+        println!(
+            "  worst: {} with {} points",
+            worst.get_mapping(),
+            worst.get_points()
+        );
+    }
+}
+
+fn print_scoring_rules_points(
+    file_name: &str,
+    scoring_file_name: &str,
+    scoring_rules: &ScoringRules,
+) {
+    match get_games_points_from_source(&mut Parser::build_v1(file_name), scoring_rules) {
+        Ok(points) => println!(
+            "Games variant 1 points (scoring rules '{}'): {}",
+            scoring_file_name, points
+        ),
+        Err(err) => eprintln!("Error while getting games points, {}", err),
+    }
+
+    match get_games_points_from_source(&mut Parser::build_v2(file_name), scoring_rules) {
+        Ok(points) => println!(
+            "Games variant 2 points (scoring rules '{}'): {}",
+            scoring_file_name, points
+        ),
+        Err(err) => eprintln!("Error while getting games points, {}", err),
+    }
+}
+
+fn print_games_report(
+    file_name: &str,
+    variant: u32,
+    report_format: ReportFormat,
+    scoring_rules: &ScoringRules,
+) {
+    let mut parser = match variant {
+        1 => Parser::build_v1(file_name),
+        _ => Parser::build_v2(file_name),
+    };
+
+    match get_games_report_from_source(&mut parser, scoring_rules) {
+        Ok(report) => print!("{}", report.format(report_format)),
+        Err(err) => eprintln!("Error while getting games report, {}", err),
+    }
+}
+
+fn convert_guide(file_name: &str, target_variant: u32, output_file_name: &str) {
+    let mut parser = match target_variant {
+        1 => Parser::build_v2(file_name),
+        _ => Parser::build_v1(file_name),
+    };
+
+    let games = match parser.try_get_games() {
+        Ok(games) => games,
+        Err(err) => {
+            eprintln!("Error while converting guide, {}", err);
+            return;
+        }
+    };
+
+    let converted_games: Vec<_> = games
+        .iter()
+        .map(|game| match target_variant {
+            1 => game.to_v1(),
+            _ => game.to_v2(),
+        })
+        .collect();
+
+    match GuideWriter::try_build_file(output_file_name)
+        .and_then(|mut writer| writer.try_write_games(&converted_games))
+    {
+        Ok(()) => println!(
+            "Converted guide to variant {}: {}",
+            target_variant, output_file_name
+        ),
+        Err(err) => eprintln!("Error while writing guide '{}', {}", output_file_name, err),
+    }
+}
+
+fn print_tournament_standings(
+    file_name: &str,
+    scoring_rules: &ScoringRules,
+    tie_breaks: &[TieBreak],
+) {
+    let standings = match get_tournament_standings(file_name, scoring_rules, tie_breaks) {
+        Ok(standings) => standings,
+        Err(err) => {
+            eprintln!("Error while getting tournament standings, {}", err);
+            return;
+        }
+    };
+
+    println!("Tournament standings:");
+
+    for (rank, standing) in standings.iter().enumerate() {
+        println!(
+            "  {}. {}: {} points ({} wins, {} ties, {} losses)",
+            rank + 1,
+            standing.get_player(),
+            standing.get_points(),
+            standing.get_number_of_wins(),
+            standing.get_number_of_ties(),
+            standing.get_number_of_losses()
+        );
+    }
+}
+
+fn print_simulation(file_name: &str, simulation: &Simulation) {
+    let opponents = [
+        ("uniform", Opponent::Uniform),
+        (
+            "frequency biased",
+            Opponent::FrequencyBiased { weights: [5, 3, 2] },
+        ),
+        ("repeat last", Opponent::RepeatLast),
+    ];
+
+    let mut strategies = vec![
+        ("counter most frequent", Strategy::CounterMostFrequent),
+        ("markov", Strategy::Markov),
+    ];
+
+    match get_guide_strategy(file_name) {
+        Ok(strategy) => strategies.insert(0, ("fixed guide", strategy)),
+        Err(err) => eprintln!("Error while reading strategy guide, {}", err),
+    }
+
+    println!("Simulations:");
+
+    for (opponent_name, opponent) in opponents.iter() {
+        for (strategy_name, strategy) in strategies.iter() {
+            let result = match simulation.try_simulate(opponent, strategy) {
+                Ok(result) => result,
+                Err(err) => {
+                    eprintln!(
+                        "Error while simulating {} vs {}, {}",
+                        strategy_name, opponent_name, err
+                    );
+                    continue;
+                }
+            };
+            println!(
+                "  {} vs {}: expected score {:.3} over {} rounds ({} wins, {} ties, {} losses)",
+                strategy_name,
+                opponent_name,
+                result.get_expected_score(),
+                result.get_number_of_rounds(),
+                result.get_number_of_wins(),
+                result.get_number_of_ties(),
+                result.get_number_of_losses()
+            );
+        }
+    }
 }
@@ -0,0 +1,294 @@
+use crate::{
+    parser::ParsingError,
+    rucksack::{
+        compartment::item_set::{ItemSet, ItemSetError},
+        Rucksack,
+    },
+};
+use std::{error, fmt};
+
+#[derive(Debug, PartialEq)]
+pub enum GroupingError {
+    InvalidGroupSize,
+    IncompleteGroup {
+        group_size: usize,
+        number_of_rucksacks: usize,
+    },
+    InvalidNumberOfRucksacks {
+        group_size: usize,
+        number_of_rucksacks: usize,
+    },
+    ItemSetError(ItemSetError),
+}
+
+impl fmt::Display for GroupingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GroupingError::InvalidGroupSize => {
+                write!(f, "grouping error, group size should be at least 1")
+            }
+            GroupingError::IncompleteGroup {
+                group_size,
+                number_of_rucksacks,
+            } => write!(
+                f,
+                "grouping error, last group has {} of {} rucksacks",
+                number_of_rucksacks, group_size
+            ),
+            GroupingError::InvalidNumberOfRucksacks {
+                group_size,
+                number_of_rucksacks,
+            } => write!(
+                f,
+                "grouping error, number of rucksacks should be between 1 and {}, but was {}",
+                group_size, number_of_rucksacks
+            ),
+            GroupingError::ItemSetError(err) => write!(f, "grouping error, {}", err),
+        }
+    }
+}
+
+impl error::Error for GroupingError {}
+
+impl From<ItemSetError> for GroupingError {
+    fn from(err: ItemSetError) -> Self {
+        GroupingError::ItemSetError(err)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BadgeError {
+    ParsingError(ParsingError),
+    GroupingError(GroupingError),
+}
+
+impl fmt::Display for BadgeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BadgeError::ParsingError(err) => write!(f, "{}", err),
+            BadgeError::GroupingError(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for BadgeError {}
+
+impl From<ParsingError> for BadgeError {
+    fn from(err: ParsingError) -> Self {
+        BadgeError::ParsingError(err)
+    }
+}
+
+impl From<GroupingError> for BadgeError {
+    fn from(err: GroupingError) -> Self {
+        BadgeError::GroupingError(err)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RucksackGroup<'a> {
+    rucksacks: &'a [Rucksack],
+}
+
+impl<'a> RucksackGroup<'a> {
+    pub fn build(rucksacks: &'a [Rucksack]) -> Self {
+        RucksackGroup { rucksacks }
+    }
+
+    pub fn try_build_groups(
+        rucksacks: &'a [Rucksack],
+        group_size: usize,
+    ) -> Result<Vec<Self>, GroupingError> {
+        if group_size == 0 {
+            return Err(GroupingError::InvalidGroupSize);
+        }
+
+        let number_of_remaining_rucksacks = rucksacks.len() % group_size;
+        if number_of_remaining_rucksacks != 0 {
+            return Err(GroupingError::IncompleteGroup {
+                group_size,
+                number_of_rucksacks: number_of_remaining_rucksacks,
+            });
+        }
+
+        Ok(rucksacks.chunks(group_size).map(Self::build).collect())
+    }
+
+    pub fn get_number_of_rucksacks(&self) -> usize {
+        self.rucksacks.len()
+    }
+
+    pub fn try_get_common_item_set(&self) -> Result<ItemSet, GroupingError> {
+        let Some((first_rucksack, rucksacks)) = self.rucksacks.split_first() else {
+            return Ok(ItemSet::new());
+        };
+
+        let mut common_item_set = first_rucksack.try_get_item_set()?;
+        for rucksack in rucksacks {
+            common_item_set = common_item_set.try_intersection(&rucksack.try_get_item_set()?)?;
+        }
+
+        Ok(common_item_set)
+    }
+
+    pub fn try_get_item_set_in_at_least(
+        &self,
+        number_of_rucksacks: usize,
+    ) -> Result<ItemSet, GroupingError> {
+        if number_of_rucksacks == 0 || number_of_rucksacks > self.rucksacks.len() {
+            return Err(GroupingError::InvalidNumberOfRucksacks {
+                group_size: self.rucksacks.len(),
+                number_of_rucksacks,
+            });
+        }
+
+        let item_sets = self
+            .rucksacks
+            .iter()
+            .map(|rucksack| rucksack.try_get_item_set())
+            .collect::<Result<Vec<ItemSet>, ItemSetError>>()?;
+
+        Ok(ItemSet::try_get_in_at_least(
+            &item_sets,
+            number_of_rucksacks,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rucksack::compartment::item_alphabet::ItemAlphabet;
+
+    fn build_rucksacks(ids: &[&str]) -> Vec<Rucksack> {
+        ids.iter()
+            .map(|ids| Rucksack::try_build(ids).unwrap())
+            .collect()
+    }
+
+    fn get_ids(item_set: &ItemSet) -> String {
+        item_set
+            .get_items()
+            .iter()
+            .map(|item| item.get_id())
+            .collect()
+    }
+
+    #[test]
+    fn build_groups() {
+        let rucksacks = build_rucksacks(&["ab", "bc", "cd", "de"]);
+
+        let groups = RucksackGroup::try_build_groups(&rucksacks, 2).unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1], RucksackGroup::build(&rucksacks[2..]));
+    }
+
+    #[test]
+    fn build_groups_with_incomplete_last_group() {
+        let rucksacks = build_rucksacks(&["ab", "bc", "cd", "de"]);
+
+        assert_eq!(
+            RucksackGroup::try_build_groups(&rucksacks, 3),
+            Err(GroupingError::IncompleteGroup {
+                group_size: 3,
+                number_of_rucksacks: 1
+            })
+        );
+    }
+
+    #[test]
+    fn build_groups_of_size_zero() {
+        let rucksacks = build_rucksacks(&["ab"]);
+
+        assert_eq!(
+            RucksackGroup::try_build_groups(&rucksacks, 0),
+            Err(GroupingError::InvalidGroupSize)
+        );
+    }
+
+    #[test]
+    fn build_groups_without_rucksacks() {
+        assert_eq!(RucksackGroup::try_build_groups(&[], 3), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_common_items() {
+        let rucksacks = build_rucksacks(&["abcd", "bcXd", "dYcb", "cZbd"]);
+
+        assert_eq!(
+            get_ids(
+                &RucksackGroup::build(&rucksacks)
+                    .try_get_common_item_set()
+                    .unwrap()
+            ),
+            "bcd"
+        );
+        assert_eq!(
+            get_ids(
+                &RucksackGroup::build(&rucksacks[..1])
+                    .try_get_common_item_set()
+                    .unwrap()
+            ),
+            "abcd"
+        );
+        assert_eq!(
+            RucksackGroup::build(&[]).try_get_common_item_set(),
+            Ok(ItemSet::new())
+        );
+    }
+
+    #[test]
+    fn test_items_of_rucksacks_of_other_alphabets() {
+        let alphabet = ItemAlphabet::try_build_from_ids("abcd").unwrap();
+        let rucksacks = vec![
+            Rucksack::try_build("abcd").unwrap(),
+            Rucksack::try_build_with_alphabet("abcd", &alphabet).unwrap(),
+        ];
+        let group = RucksackGroup::build(&rucksacks);
+        let error = GroupingError::ItemSetError(ItemSetError::DifferentAlphabets);
+
+        assert_eq!(group.try_get_common_item_set(), Err(error));
+        assert_eq!(
+            group
+                .try_get_item_set_in_at_least(1)
+                .unwrap_err()
+                .to_string(),
+            "grouping error, item set error, item sets of different alphabets cannot be combined"
+        );
+    }
+
+    #[test]
+    fn test_items_in_at_least_some_rucksacks() {
+        let rucksacks = build_rucksacks(&["aXbY", "abcd", "aXcZ"]);
+        let group = RucksackGroup::build(&rucksacks);
+
+        let get_ids_in_at_least = |number_of_rucksacks| {
+            get_ids(
+                &group
+                    .try_get_item_set_in_at_least(number_of_rucksacks)
+                    .unwrap(),
+            )
+        };
+
+        assert_eq!(get_ids_in_at_least(1), "abcdXYZ");
+        assert_eq!(get_ids_in_at_least(2), "abcX");
+        assert_eq!(get_ids_in_at_least(3), "a");
+    }
+
+    #[test]
+    fn test_items_in_invalid_number_of_rucksacks() {
+        let rucksacks = build_rucksacks(&["aXbY", "abcd", "aXcZ"]);
+        let group = RucksackGroup::build(&rucksacks);
+
+        for number_of_rucksacks in [0, 4] {
+            assert_eq!(
+                group.try_get_item_set_in_at_least(number_of_rucksacks),
+                Err(GroupingError::InvalidNumberOfRucksacks {
+                    group_size: 3,
+                    number_of_rucksacks
+                })
+            );
+        }
+    }
+}
@@ -1,18 +1,34 @@
+mod group;
 mod parser;
 mod rucksack;
 
+pub use group::{BadgeError, GroupingError, RucksackGroup};
+pub use parser::ParsingError;
+pub use rucksack::{
+    compartment::{
+        item::{InvalidCharacterError, Item},
+        item_alphabet::{ItemAlphabet, ItemAlphabetError},
+        item_set::{ItemSet, ItemSetError},
+        Compartment, CompartmentError,
+    },
+    Rucksack, RucksackError,
+};
+
 use parser::Parser;
-use rucksack::Rucksack;
 
-pub fn get_sum_of_properties_1(file_name: &str) -> u32 {
-    let rucksack_parser = Parser::build(file_name);
+pub fn get_sum_of_properties_1(file_name: &str) -> u64 {
+    get_sum_of_common_item_priorities(file_name, &ItemAlphabet::default())
+}
+
+pub fn get_sum_of_common_item_priorities(file_name: &str, alphabet: &ItemAlphabet) -> u64 {
+    let rucksack_parser = Parser::build(file_name).with_alphabet(alphabet.clone());
     let rucksacks = rucksack_parser.try_get_rucksacks();
 
     match rucksacks {
         Ok(rucksacks) => rucksacks
             .iter()
             .filter_map(|rucksack| rucksack.get_first_common_item_of_compartments())
-            .map(|item| item.get_priority())
+            .map(|item| u64::from(item.get_priority()))
             .sum(),
         Err(err) => {
             println!("Error while getting sum of properties, {}", err);
@@ -21,36 +37,36 @@ pub fn get_sum_of_properties_1(file_name: &str) -> u32 {
     }
 }
 
-pub fn get_sum_of_properties_2(file_name: &str) -> u32 {
-    let rucksack_parser = Parser::build(file_name);
-    let rucksacks = rucksack_parser.try_get_rucksacks();
-
-    let rucksacks = match rucksacks {
-        Ok(rucksacks) => rucksacks,
+pub fn get_sum_of_properties_2(file_name: &str) -> u64 {
+    match try_get_sum_of_badge_priorities(file_name, &ItemAlphabet::default(), 3, 3) {
+        Ok(sum) => sum,
         Err(err) => {
             println!("Error while getting sum of properties, {}", err);
-            return 0;
+            0
         }
-    };
-
-    let mut sum = 0;
-
-    for i in (0..rucksacks.len()).step_by(3) {
-        let rucksack_1 = &rucksacks[i];
-        let rucksack_2 = &rucksacks[i + 1];
-        let rucksack_3 = &rucksacks[i + 2];
+    }
+}
 
-        let common_items = match Rucksack::get_common_items(rucksack_1, rucksack_2, rucksack_3) {
-            Some(common_items) => common_items,
-            None => continue,
-        };
+pub fn try_get_sum_of_badge_priorities(
+    file_name: &str,
+    alphabet: &ItemAlphabet,
+    group_size: usize,
+    number_of_rucksacks_with_badge: usize,
+) -> Result<u64, BadgeError> {
+    let rucksack_parser = Parser::build(file_name).with_alphabet(alphabet.clone());
+    let rucksacks = rucksack_parser.try_get_rucksacks()?;
 
-        for common_item in common_items {
-            sum += common_item.get_priority();
-        }
+    let mut sum = 0;
+    for group in RucksackGroup::try_build_groups(&rucksacks, group_size)? {
+        sum += group
+            .try_get_item_set_in_at_least(number_of_rucksacks_with_badge)?
+            .get_items()
+            .iter()
+            .map(|item| u64::from(item.get_priority()))
+            .sum::<u64>();
     }
 
-    sum
+    Ok(sum)
 }
 
 #[cfg(test)]
@@ -92,4 +108,95 @@ mod tests {
         let file_name = "../input/invalid_test_input.txt";
         assert_eq!(get_sum_of_properties_2(file_name), 0);
     }
+
+    #[test]
+    fn test_incomplete_group_file_2() {
+        let file_name = "../input/incomplete_group_test_input.txt";
+        assert_eq!(get_sum_of_properties_2(file_name), 0);
+    }
+
+    #[test]
+    fn test_badges_in_groups_of_two() {
+        let file_name = "../input/test_input.txt";
+        assert_eq!(
+            try_get_sum_of_badge_priorities(file_name, &ItemAlphabet::default(), 2, 2),
+            Ok(371)
+        );
+    }
+
+    #[test]
+    fn test_badges_in_two_of_three_rucksacks() {
+        let file_name = "../input/test_input.txt";
+        let alphabet = ItemAlphabet::default();
+
+        assert_eq!(
+            try_get_sum_of_badge_priorities(file_name, &alphabet, 3, 3),
+            Ok(70)
+        );
+        assert_eq!(
+            try_get_sum_of_badge_priorities(file_name, &alphabet, 3, 2),
+            Ok(571)
+        );
+    }
+
+    #[test]
+    fn test_badge_errors() {
+        let file_name = "../input/incomplete_group_test_input.txt";
+        let alphabet = ItemAlphabet::default();
+
+        assert_eq!(
+            try_get_sum_of_badge_priorities(file_name, &alphabet, 3, 3),
+            Err(BadgeError::GroupingError(GroupingError::IncompleteGroup {
+                group_size: 3,
+                number_of_rucksacks: 1
+            }))
+        );
+        assert_eq!(
+            try_get_sum_of_badge_priorities("../input/test_input.txt", &alphabet, 3, 4),
+            Err(BadgeError::GroupingError(
+                GroupingError::InvalidNumberOfRucksacks {
+                    group_size: 3,
+                    number_of_rucksacks: 4
+                }
+            ))
+        );
+        assert!(matches!(
+            try_get_sum_of_badge_priorities("../input/missing_test_input.txt", &alphabet, 3, 3),
+            Err(BadgeError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn test_priorities_beyond_u32() {
+        let file_name = "../input/test_input.txt";
+        let ids_and_priorities: Vec<(char, u32)> = ('a'..='z')
+            .chain('A'..='Z')
+            .map(|id| (id, u32::MAX))
+            .collect();
+        let alphabet = ItemAlphabet::try_build(&ids_and_priorities).unwrap();
+
+        assert_eq!(
+            get_sum_of_common_item_priorities(file_name, &alphabet),
+            6 * u64::from(u32::MAX)
+        );
+        assert_eq!(
+            try_get_sum_of_badge_priorities(file_name, &alphabet, 3, 3),
+            Ok(2 * u64::from(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_digit_alphabet() {
+        let file_name = "../input/digit_test_input.txt";
+        let alphabet =
+            ItemAlphabet::try_build(&('0'..='9').zip((10..).step_by(10)).collect::<Vec<_>>())
+                .unwrap();
+
+        assert_eq!(get_sum_of_properties_1(file_name), 0);
+        assert_eq!(get_sum_of_common_item_priorities(file_name, &alphabet), 140);
+        assert_eq!(
+            try_get_sum_of_badge_priorities(file_name, &alphabet, 3, 3),
+            Ok(20)
+        );
+    }
 }
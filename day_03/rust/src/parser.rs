@@ -1,4 +1,4 @@
-use crate::rucksack::{Rucksack, RucksackError};
+use crate::rucksack::{compartment::item_alphabet::ItemAlphabet, Rucksack, RucksackError};
 
 use std::{
     error, fmt, fs,
@@ -50,15 +50,21 @@ impl ParsingError {
 
 pub struct Parser {
     file_name: String,
+    alphabet: ItemAlphabet,
 }
 
 impl Parser {
     pub fn build(file_name: &str) -> Parser {
         Parser {
             file_name: String::from(file_name),
+            alphabet: ItemAlphabet::default(),
         }
     }
 
+    pub fn with_alphabet(self, alphabet: ItemAlphabet) -> Self {
+        Parser { alphabet, ..self }
+    }
+
     pub fn try_get_rucksacks(&self) -> Result<Vec<Rucksack>, ParsingError> {
         let file = self.try_open_file()?;
 
@@ -95,7 +101,7 @@ impl Parser {
         line_index: usize,
         line_content: String,
     ) -> Result<Rucksack, ParsingError> {
-        match Rucksack::try_build(&line_content) {
+        match Rucksack::try_build_with_alphabet(&line_content, &self.alphabet) {
             Ok(rucksack) => Ok(rucksack),
             Err(err) => Err(ParsingError::build_parsing_rucksack_error(
                 line_index + 1,
@@ -157,7 +163,28 @@ mod tests {
                 cause: format!(
                     "second compartment error, {}, {}",
                     "compartment error at position '5'",
-                    "invalid character error, should be ascii alphanumeric (a-z, A-Z), but was '!'",
+                    "invalid character error, should be one of (a-z, A-Z), but was '!'",
+                )
+            })
+        );
+    }
+
+    #[test]
+    fn test_file_with_other_alphabet() {
+        let file_name = "../input/test_input.txt";
+
+        let rucksack_parser = Parser::build(file_name)
+            .with_alphabet(ItemAlphabet::try_build_from_ids("abc").unwrap());
+        let rucksacks = rucksack_parser.try_get_rucksacks();
+
+        assert_eq!(
+            rucksacks,
+            Err(ParsingError::ParsingRucksackError {
+                line_index: 1,
+                cause: format!(
+                    "first compartment error, {}, {}",
+                    "compartment error at position '1'",
+                    "invalid character error, should be one of (a-c), but was 'v'",
                 )
             })
         );
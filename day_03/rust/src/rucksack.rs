@@ -1,8 +1,10 @@
-mod compartment;
+pub mod compartment;
 
 use std::{error, fmt};
 
 use compartment::item::Item;
+use compartment::item_alphabet::ItemAlphabet;
+use compartment::item_set::{ItemSet, ItemSetError};
 use compartment::{Compartment, CompartmentError};
 
 #[derive(Debug, PartialEq)]
@@ -48,17 +50,26 @@ pub struct Rucksack {
 
 impl Rucksack {
     pub fn try_build(ids: &str) -> Result<Self, RucksackError> {
+        Self::try_build_with_alphabet(ids, &ItemAlphabet::default())
+    }
+
+    pub fn try_build_with_alphabet(
+        ids: &str,
+        alphabet: &ItemAlphabet,
+    ) -> Result<Self, RucksackError> {
         let (first_items_half, second_items_half) = ids.split_at(Self::get_mid_position(ids));
 
-        let first_compartment = match Compartment::try_build(first_items_half) {
-            Ok(first_compartment) => first_compartment,
-            Err(err) => return Err(RucksackError::build_first_compartment_error(err)),
-        };
+        let first_compartment =
+            match Compartment::try_build_with_alphabet(first_items_half, alphabet) {
+                Ok(first_compartment) => first_compartment,
+                Err(err) => return Err(RucksackError::build_first_compartment_error(err)),
+            };
 
-        let second_compartment = match Compartment::try_build(second_items_half) {
-            Ok(second_compartment) => second_compartment,
-            Err(err) => return Err(RucksackError::build_second_compartment_error(err)),
-        };
+        let second_compartment =
+            match Compartment::try_build_with_alphabet(second_items_half, alphabet) {
+                Ok(second_compartment) => second_compartment,
+                Err(err) => return Err(RucksackError::build_second_compartment_error(err)),
+            };
 
         Ok(Rucksack {
             first_compartment,
@@ -66,6 +77,7 @@ impl Rucksack {
         })
     }
 
+    #[allow(clippy::manual_is_multiple_of)]
     fn get_mid_position(ids: &str) -> usize {
         if ids.len() % 2 == 0 {
             ids.len() / 2
@@ -79,31 +91,44 @@ impl Rucksack {
             .get_first_common_item(&self.second_compartment)
     }
 
-    pub fn get_common_items(
+    pub fn try_get_item_set(&self) -> Result<ItemSet, ItemSetError> {
+        self.first_compartment
+            .get_item_set()
+            .try_union(self.second_compartment.get_item_set())
+    }
+
+    pub fn try_get_common_item_set_of_compartments(&self) -> Result<ItemSet, ItemSetError> {
+        self.first_compartment
+            .get_item_set()
+            .try_intersection(self.second_compartment.get_item_set())
+    }
+
+    // rucksacks of different alphabets have no common items to look for
+    pub fn try_get_common_items(
         rucksack_1: &Rucksack,
         rucksack_2: &Rucksack,
         rucksack_3: &Rucksack,
-    ) -> Option<Vec<Item>> {
-        let rucksack_1_items = rucksack_1.get_all_items();
-        let rucksack_2_items = rucksack_2.get_all_items();
-        let rucksack_3_items = rucksack_3.get_all_items();
-
-        let common_items =
-            Self::get_common_items_vector(rucksack_1_items, rucksack_2_items, rucksack_3_items);
-
-        if common_items.is_empty() {
-            None
+    ) -> Result<Option<Vec<Item>>, ItemSetError> {
+        let common_item_set = rucksack_1
+            .try_get_item_set()?
+            .try_intersection(&rucksack_2.try_get_item_set()?)?
+            .try_intersection(&rucksack_3.try_get_item_set()?)?;
+
+        if common_item_set.is_empty() {
+            Ok(None)
         } else {
-            Some(common_items)
+            Ok(Some(common_item_set.get_items()))
         }
     }
 
+    #[cfg(test)]
     fn get_all_items(&self) -> Vec<Item> {
         let mut items = self.first_compartment.get_items();
         items.extend(self.second_compartment.get_items());
         items
     }
 
+    #[cfg(test)]
     fn get_common_items_vector(
         items_1: Vec<Item>,
         items_2: Vec<Item>,
@@ -126,6 +151,22 @@ mod tests {
 
     use super::*;
 
+    // groups of three rucksacks with no, one, several and repeated common items
+    const COMMON_ITEMS_FIXTURES: [[&str; 3]; 6] = [
+        ["abcdef", "ghijkl", "mnopqr"],
+        ["abXcdY", "XefgZh", "ijXklm"],
+        ["aabbcc", "cbaabc", "bcacab"],
+        ["XYZxyz", "zyxZYX", "xXyYzZ"],
+        ["abcdeXf", "fXedcba", "Xf"],
+        ["aAbBcCdD", "DdCcBbAa", "aD"],
+    ];
+
+    fn get_sorted_ids(items: &[Item]) -> Vec<char> {
+        let mut ids: Vec<char> = items.iter().map(|item| item.get_id()).collect();
+        ids.sort();
+        ids
+    }
+
     #[test]
     fn build_rucksack_with_no_items() {
         let ids = "";
@@ -201,8 +242,8 @@ mod tests {
         let rucksack_3 = Rucksack::try_build(ids_3).unwrap();
 
         assert_eq!(
-            Rucksack::get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
-            None
+            Rucksack::try_get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
+            Ok(None)
         );
     }
 
@@ -218,8 +259,8 @@ mod tests {
         let expected_items = vec![Item::try_build('d').unwrap()];
 
         assert_eq!(
-            Rucksack::get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
-            Some(expected_items)
+            Rucksack::try_get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
+            Ok(Some(expected_items))
         );
     }
 
@@ -235,8 +276,8 @@ mod tests {
         let expected_items = vec![Item::try_build('c').unwrap(), Item::try_build('d').unwrap()];
 
         assert_eq!(
-            Rucksack::get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
-            Some(expected_items)
+            Rucksack::try_get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
+            Ok(Some(expected_items))
         );
     }
 
@@ -256,8 +297,77 @@ mod tests {
         ];
 
         assert_eq!(
-            Rucksack::get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
-            Some(expected_items)
+            Rucksack::try_get_common_items(&rucksack_1, &rucksack_2, &rucksack_3),
+            Ok(Some(expected_items))
         );
     }
+
+    #[test]
+    fn test_item_sets() {
+        let rucksack = Rucksack::try_build("abcCbA").unwrap();
+
+        let ids: Vec<char> = rucksack
+            .try_get_item_set()
+            .unwrap()
+            .get_items()
+            .iter()
+            .map(|item| item.get_id())
+            .collect();
+        let common_ids: Vec<char> = rucksack
+            .try_get_common_item_set_of_compartments()
+            .unwrap()
+            .get_items()
+            .iter()
+            .map(|item| item.get_id())
+            .collect();
+
+        assert_eq!(ids, vec!['a', 'b', 'c', 'A', 'C']);
+        assert_eq!(common_ids, vec!['b']);
+    }
+
+    #[test]
+    fn test_common_items_of_other_alphabets() {
+        let alphabet = ItemAlphabet::try_build_from_ids("abcd").unwrap();
+        let rucksack = Rucksack::try_build("abcd").unwrap();
+        let other_rucksack = Rucksack::try_build_with_alphabet("abcd", &alphabet).unwrap();
+
+        assert_eq!(
+            Rucksack::try_get_common_items(&rucksack, &rucksack, &other_rucksack),
+            Err(ItemSetError::DifferentAlphabets)
+        );
+    }
+
+    #[test]
+    fn test_common_items_match_reference() {
+        for ids in COMMON_ITEMS_FIXTURES {
+            let rucksacks: Vec<Rucksack> = ids
+                .iter()
+                .map(|ids| Rucksack::try_build(ids).unwrap())
+                .collect();
+
+            let common_items =
+                Rucksack::try_get_common_items(&rucksacks[0], &rucksacks[1], &rucksacks[2])
+                    .unwrap()
+                    .unwrap_or_default();
+            let reference_common_items = Rucksack::get_common_items_vector(
+                rucksacks[0].get_all_items(),
+                rucksacks[1].get_all_items(),
+                rucksacks[2].get_all_items(),
+            );
+
+            assert_eq!(
+                get_sorted_ids(&common_items),
+                get_sorted_ids(&reference_common_items)
+            );
+
+            for rucksack in rucksacks.iter() {
+                assert_eq!(
+                    rucksack.get_first_common_item_of_compartments(),
+                    rucksack
+                        .first_compartment
+                        .get_first_common_item_by_scanning(&rucksack.second_compartment)
+                );
+            }
+        }
+    }
 }
@@ -1,6 +1,10 @@
 pub mod item;
+pub mod item_alphabet;
+pub mod item_set;
 
-use item::{InvalidCharacterError, Item};
+use item::Item;
+use item_alphabet::ItemAlphabet;
+use item_set::ItemSet;
 use std::{error, fmt};
 
 #[derive(Debug, PartialEq)]
@@ -22,7 +26,7 @@ impl fmt::Display for CompartmentError {
 impl error::Error for CompartmentError {}
 
 impl CompartmentError {
-    fn build(position: usize, err: InvalidCharacterError) -> Self {
+    fn build<E: error::Error>(position: usize, err: E) -> Self {
         CompartmentError {
             position,
             cause: err.to_string(),
@@ -33,33 +37,62 @@ impl CompartmentError {
 #[derive(Debug, PartialEq)]
 pub struct Compartment {
     items: Vec<Item>,
+    item_set: ItemSet,
 }
 
 impl Compartment {
     pub fn try_build(ids: &str) -> Result<Self, CompartmentError> {
+        Self::try_build_with_alphabet(ids, &ItemAlphabet::default())
+    }
+
+    pub fn try_build_with_alphabet(
+        ids: &str,
+        alphabet: &ItemAlphabet,
+    ) -> Result<Self, CompartmentError> {
         let mut items = Vec::new();
+        let mut item_set = ItemSet::new_with_alphabet(alphabet);
 
         for (position, id) in ids.chars().enumerate() {
-            match Item::try_build(id) {
-                Ok(item) => items.push(item),
+            let item = match Item::try_build_with_alphabet(id, alphabet) {
+                Ok(item) => item,
                 Err(err) => return Err(CompartmentError::build(position + 1, err)),
+            };
+
+            if let Err(err) = item_set.try_insert(&item) {
+                return Err(CompartmentError::build(position + 1, err));
             }
+
+            items.push(item);
         }
 
-        Ok(Compartment { items })
+        Ok(Compartment { items, item_set })
     }
 
     pub fn get_first_common_item(&self, other: &Compartment) -> Option<&Item> {
-        self.items.iter().find(|item| other.contains(item.get_id()))
+        self.items.iter().find(|item| other.contains(item))
     }
 
-    fn contains(&self, item_id: char) -> bool {
-        self.items.iter().any(|item| item.get_id() == item_id)
+    fn contains(&self, item: &Item) -> bool {
+        self.item_set.contains(item)
     }
 
     pub fn get_items(&self) -> Vec<Item> {
         self.items.clone()
     }
+
+    pub fn get_item_set(&self) -> &ItemSet {
+        &self.item_set
+    }
+
+    #[cfg(test)]
+    pub fn get_first_common_item_by_scanning(&self, other: &Compartment) -> Option<&Item> {
+        self.items.iter().find(|item| {
+            other
+                .items
+                .iter()
+                .any(|other_item| other_item.get_id() == item.get_id())
+        })
+    }
 }
 
 #[cfg(test)]
@@ -71,7 +104,10 @@ mod tests {
         let ids = "";
         let compartment = Compartment::try_build(ids);
 
-        let expected_compartment = Compartment { items: vec![] };
+        let expected_compartment = Compartment {
+            items: vec![],
+            item_set: ItemSet::new(),
+        };
 
         assert_eq!(compartment, Ok(expected_compartment));
     }
@@ -81,8 +117,10 @@ mod tests {
         let ids = "aSfiIOJFdhspoK";
         let compartment = Compartment::try_build(ids);
 
+        let items: Vec<Item> = ids.chars().map(|id| Item::try_build(id).unwrap()).collect();
         let expected_compartment = Compartment {
-            items: ids.chars().map(|id| Item::try_build(id).unwrap()).collect(),
+            item_set: ItemSet::try_build(&items).unwrap(),
+            items,
         };
 
         assert_eq!(compartment, Ok(expected_compartment));
@@ -93,8 +131,10 @@ mod tests {
         let ids = "TiiaTAiATaTAiT";
         let compartment = Compartment::try_build(ids);
 
+        let items: Vec<Item> = ids.chars().map(|id| Item::try_build(id).unwrap()).collect();
         let expected_compartment = Compartment {
-            items: ids.chars().map(|id| Item::try_build(id).unwrap()).collect(),
+            item_set: ItemSet::try_build(&items).unwrap(),
+            items,
         };
 
         assert_eq!(compartment, Ok(expected_compartment));
@@ -110,7 +150,7 @@ mod tests {
             Err(CompartmentError {
                 position: 1,
                 cause: String::from(
-                    "invalid character error, should be ascii alphanumeric (a-z, A-Z), but was '2'"
+                    "invalid character error, should be one of (a-z, A-Z), but was '2'"
                 )
             })
         )
@@ -126,7 +166,7 @@ mod tests {
             Err(CompartmentError {
                 position: 1,
                 cause: String::from(
-                    "invalid character error, should be ascii alphanumeric (a-z, A-Z), but was '!'"
+                    "invalid character error, should be one of (a-z, A-Z), but was '!'"
                 )
             })
         )
@@ -142,7 +182,7 @@ mod tests {
             Err(CompartmentError {
                 position: 1,
                 cause: String::from(
-                    "invalid character error, should be ascii alphanumeric (a-z, A-Z), but was 'è'"
+                    "invalid character error, should be one of (a-z, A-Z), but was 'è'"
                 )
             })
         )
@@ -158,7 +198,7 @@ mod tests {
             Err(CompartmentError {
                 position: 7,
                 cause: String::from(
-                    "invalid character error, should be ascii alphanumeric (a-z, A-Z), but was '9'"
+                    "invalid character error, should be one of (a-z, A-Z), but was '9'"
                 )
             })
         )
@@ -1,16 +1,18 @@
+use super::item_alphabet::ItemAlphabet;
 use std::{error, fmt};
 
 #[derive(Debug, PartialEq)]
 pub struct InvalidCharacterError {
     invalid_character: char,
+    allowed_characters: String,
 }
 
 impl fmt::Display for InvalidCharacterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "invalid character error, should be ascii alphanumeric (a-z, A-Z), but was '{}'",
-            self.invalid_character
+            "invalid character error, should be one of ({}), but was '{}'",
+            self.allowed_characters, self.invalid_character
         )
     }
 }
@@ -18,22 +20,37 @@ impl fmt::Display for InvalidCharacterError {
 impl error::Error for InvalidCharacterError {}
 
 impl InvalidCharacterError {
-    fn build(invalid_character: char) -> Self {
-        InvalidCharacterError { invalid_character }
+    fn build(invalid_character: char, alphabet: &ItemAlphabet) -> Self {
+        InvalidCharacterError {
+            invalid_character,
+            allowed_characters: alphabet.get_description(),
+        }
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Item {
     id: char,
+    index: usize,
+    priority: u32,
 }
 
 impl Item {
     pub fn try_build(id: char) -> Result<Self, InvalidCharacterError> {
-        if id.is_ascii_alphabetic() {
-            Ok(Item { id })
-        } else {
-            Err(InvalidCharacterError::build(id))
+        Self::try_build_with_alphabet(id, &ItemAlphabet::default())
+    }
+
+    pub fn try_build_with_alphabet(
+        id: char,
+        alphabet: &ItemAlphabet,
+    ) -> Result<Self, InvalidCharacterError> {
+        match alphabet.get_index(id) {
+            Some(index) => Ok(Item {
+                id,
+                index,
+                priority: alphabet.get_priority(index).unwrap_or_default(),
+            }),
+            None => Err(InvalidCharacterError::build(id, alphabet)),
         }
     }
 
@@ -42,11 +59,11 @@ impl Item {
     }
 
     pub fn get_priority(&self) -> u32 {
-        if self.id.is_lowercase() {
-            self.id as u32 - 'a' as u32 + 1
-        } else {
-            self.id as u32 - 'A' as u32 + 27
-        }
+        self.priority
+    }
+
+    pub(crate) fn get_index(&self) -> usize {
+        self.index
     }
 }
 
@@ -54,13 +71,18 @@ impl Item {
 mod tests {
     use super::*;
 
+    fn build_invalid_character_error(invalid_character: char) -> InvalidCharacterError {
+        InvalidCharacterError {
+            invalid_character,
+            allowed_characters: String::from("a-z, A-Z"),
+        }
+    }
+
     #[test]
     fn build_item_from_space() {
         assert_eq!(
             Item::try_build(' '),
-            Err(InvalidCharacterError {
-                invalid_character: ' '
-            })
+            Err(build_invalid_character_error(' '))
         );
     }
 
@@ -68,10 +90,8 @@ mod tests {
     fn build_item_from_lowercase_letters() {
         for lowercase_letter in 'a'..='z' {
             assert_eq!(
-                Item::try_build(lowercase_letter),
-                Ok(Item {
-                    id: lowercase_letter
-                })
+                Item::try_build(lowercase_letter).map(|item| item.get_id()),
+                Ok(lowercase_letter)
             );
         }
     }
@@ -80,10 +100,8 @@ mod tests {
     fn build_item_from_uppercase_letters() {
         for uppercase_letter in 'A'..='Z' {
             assert_eq!(
-                Item::try_build(uppercase_letter),
-                Ok(Item {
-                    id: uppercase_letter
-                })
+                Item::try_build(uppercase_letter).map(|item| item.get_id()),
+                Ok(uppercase_letter)
             );
         }
     }
@@ -93,9 +111,7 @@ mod tests {
         for digit in '0'..='9' {
             assert_eq!(
                 Item::try_build(digit),
-                Err(InvalidCharacterError {
-                    invalid_character: digit,
-                })
+                Err(build_invalid_character_error(digit))
             );
         }
     }
@@ -105,9 +121,7 @@ mod tests {
         for special_sign in " !@#$%^&*()_-+={}[]".chars() {
             assert_eq!(
                 Item::try_build(special_sign),
-                Err(InvalidCharacterError {
-                    invalid_character: special_sign,
-                })
+                Err(build_invalid_character_error(special_sign))
             )
         }
     }
@@ -117,14 +131,13 @@ mod tests {
         for special_letter in "èñàùéáúäöüßç".chars() {
             assert_eq!(
                 Item::try_build(special_letter),
-                Err(InvalidCharacterError {
-                    invalid_character: special_letter,
-                })
+                Err(build_invalid_character_error(special_letter))
             )
         }
     }
 
     #[test]
+    #[allow(clippy::explicit_counter_loop)]
     fn test_lowercase_priorities() {
         let mut expected_priority = 1;
 
@@ -140,6 +153,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::explicit_counter_loop)]
     fn test_uppercase_priorities() {
         let mut expected_priority = 27;
 
@@ -153,4 +167,20 @@ mod tests {
             expected_priority += 1;
         }
     }
+
+    #[test]
+    fn build_item_with_alphabet() {
+        let alphabet = ItemAlphabet::try_build(&[('7', 10), ('a', 20)]).unwrap();
+
+        let item = Item::try_build_with_alphabet('a', &alphabet).unwrap();
+        assert_eq!(item.get_priority(), 20);
+        assert_eq!(item.get_index(), 1);
+
+        assert_eq!(
+            Item::try_build_with_alphabet('b', &alphabet)
+                .unwrap_err()
+                .to_string(),
+            "invalid character error, should be one of (7, a), but was 'b'"
+        );
+    }
 }
@@ -0,0 +1,242 @@
+use std::{
+    collections::HashMap,
+    error, fmt,
+    sync::{Arc, OnceLock},
+};
+
+const MAX_NUMBER_OF_IDS: usize = u64::BITS as usize;
+const NUMBER_OF_ASCII_IDS: usize = 128;
+
+static DEFAULT_ITEM_ALPHABET: OnceLock<ItemAlphabet> = OnceLock::new();
+
+#[derive(Debug, PartialEq)]
+pub enum ItemAlphabetError {
+    NoIds,
+    TooManyIds { number_of_ids: usize },
+    DuplicateId { id: char },
+}
+
+impl fmt::Display for ItemAlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ItemAlphabetError::NoIds => {
+                write!(f, "item alphabet error, should have at least one id")
+            }
+            ItemAlphabetError::TooManyIds { number_of_ids } => write!(
+                f,
+                "item alphabet error, should have at most {} ids, but had {}",
+                MAX_NUMBER_OF_IDS, number_of_ids
+            ),
+            ItemAlphabetError::DuplicateId { id } => {
+                write!(f, "item alphabet error, id '{}' is declared twice", id)
+            }
+        }
+    }
+}
+
+impl error::Error for ItemAlphabetError {}
+
+// ascii ids are looked up in a table, any other id in a map
+#[derive(Debug, PartialEq)]
+struct Ids {
+    ids: Vec<char>,
+    priorities: Vec<u32>,
+    ascii_indices: [Option<u8>; NUMBER_OF_ASCII_IDS],
+    other_indices: HashMap<char, usize>,
+}
+
+// the ids are shared, so that every item set can keep the alphabet it was built with
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemAlphabet {
+    ids: Arc<Ids>,
+}
+
+impl Default for ItemAlphabet {
+    fn default() -> Self {
+        DEFAULT_ITEM_ALPHABET
+            .get_or_init(|| {
+                let ids: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+                Self::build(ids, (1..=52).collect())
+            })
+            .clone()
+    }
+}
+
+impl ItemAlphabet {
+    pub fn try_build(ids_and_priorities: &[(char, u32)]) -> Result<Self, ItemAlphabetError> {
+        if ids_and_priorities.is_empty() {
+            return Err(ItemAlphabetError::NoIds);
+        }
+
+        if ids_and_priorities.len() > MAX_NUMBER_OF_IDS {
+            return Err(ItemAlphabetError::TooManyIds {
+                number_of_ids: ids_and_priorities.len(),
+            });
+        }
+
+        let mut ids = Vec::new();
+        let mut priorities = Vec::new();
+
+        for (id, priority) in ids_and_priorities {
+            if ids.contains(id) {
+                return Err(ItemAlphabetError::DuplicateId { id: *id });
+            }
+
+            ids.push(*id);
+            priorities.push(*priority);
+        }
+
+        Ok(Self::build(ids, priorities))
+    }
+
+    fn build(ids: Vec<char>, priorities: Vec<u32>) -> Self {
+        let mut ascii_indices = [None; NUMBER_OF_ASCII_IDS];
+        let mut other_indices = HashMap::new();
+
+        for (index, id) in ids.iter().enumerate() {
+            match ascii_indices.get_mut(*id as usize) {
+                Some(ascii_index) => *ascii_index = Some(index as u8),
+                None => {
+                    other_indices.insert(*id, index);
+                }
+            }
+        }
+
+        ItemAlphabet {
+            ids: Arc::new(Ids {
+                ids,
+                priorities,
+                ascii_indices,
+                other_indices,
+            }),
+        }
+    }
+
+    // the priorities follow the order of the ids, starting at 1
+    pub fn try_build_from_ids(ids: &str) -> Result<Self, ItemAlphabetError> {
+        let ids_and_priorities: Vec<(char, u32)> = ids.chars().zip(1..).collect();
+        Self::try_build(&ids_and_priorities)
+    }
+
+    pub fn get_number_of_ids(&self) -> usize {
+        self.ids.ids.len()
+    }
+
+    pub fn get_index(&self, id: char) -> Option<usize> {
+        match self.ids.ascii_indices.get(id as usize) {
+            Some(ascii_index) => ascii_index.map(usize::from),
+            None => self.ids.other_indices.get(&id).copied(),
+        }
+    }
+
+    pub fn get_id(&self, index: usize) -> Option<char> {
+        self.ids.ids.get(index).copied()
+    }
+
+    pub fn get_priority(&self, index: usize) -> Option<u32> {
+        self.ids.priorities.get(index).copied()
+    }
+
+    pub fn is_same(&self, other: &ItemAlphabet) -> bool {
+        Arc::ptr_eq(&self.ids, &other.ids) || self.ids == other.ids
+    }
+
+    // consecutive ids of three or more are shortened to a range, like 'a-z'
+    pub fn get_description(&self) -> String {
+        let mut runs: Vec<(char, char)> = Vec::new();
+
+        for id in self.ids.ids.iter() {
+            match runs.last_mut() {
+                Some((_, last_id)) if char::from_u32(*last_id as u32 + 1) == Some(*id) => {
+                    *last_id = *id
+                }
+                _ => runs.push((*id, *id)),
+            }
+        }
+
+        let descriptions: Vec<String> = runs
+            .iter()
+            .flat_map(|(first_id, last_id)| {
+                if *last_id as u32 - *first_id as u32 >= 2 {
+                    vec![format!("{}-{}", first_id, last_id)]
+                } else {
+                    (*first_id..=*last_id).map(String::from).collect()
+                }
+            })
+            .collect();
+
+        descriptions.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_default_alphabet() {
+        let alphabet = ItemAlphabet::default();
+
+        assert_eq!(alphabet.get_number_of_ids(), 52);
+        assert_eq!(alphabet.get_index('c'), Some(2));
+        assert_eq!(alphabet.get_priority(2), Some(3));
+        assert_eq!(alphabet.get_index('C'), Some(28));
+        assert_eq!(alphabet.get_priority(28), Some(29));
+        assert_eq!(alphabet.get_index('3'), None);
+        assert_eq!(alphabet.get_description(), "a-z, A-Z");
+    }
+
+    #[test]
+    fn build_alphabet_from_ids() {
+        let alphabet = ItemAlphabet::try_build_from_ids("0123456789abxZ").unwrap();
+
+        assert_eq!(alphabet.get_index('0'), Some(0));
+        assert_eq!(alphabet.get_priority(0), Some(1));
+        assert_eq!(alphabet.get_id(13), Some('Z'));
+        assert_eq!(alphabet.get_priority(13), Some(14));
+        assert_eq!(alphabet.get_description(), "0-9, a, b, x, Z");
+    }
+
+    #[test]
+    fn build_alphabet_with_priorities() {
+        let alphabet = ItemAlphabet::try_build(&[('#', 100), ('1', 5)]).unwrap();
+
+        assert_eq!(alphabet.get_index('1'), Some(1));
+        assert_eq!(alphabet.get_priority(1), Some(5));
+        assert_eq!(alphabet.get_priority(2), None);
+    }
+
+    #[test]
+    fn build_alphabet_with_other_ids() {
+        let alphabet = ItemAlphabet::try_build_from_ids("aé€z").unwrap();
+
+        assert_eq!(alphabet.get_index('a'), Some(0));
+        assert_eq!(alphabet.get_index('é'), Some(1));
+        assert_eq!(alphabet.get_index('€'), Some(2));
+        assert_eq!(alphabet.get_index('z'), Some(3));
+        assert_eq!(alphabet.get_index('b'), None);
+        assert_eq!(alphabet.get_index('ü'), None);
+    }
+
+    #[test]
+    fn build_invalid_alphabets() {
+        assert_eq!(
+            ItemAlphabet::try_build_from_ids(""),
+            Err(ItemAlphabetError::NoIds)
+        );
+        assert_eq!(
+            ItemAlphabet::try_build_from_ids("abca"),
+            Err(ItemAlphabetError::DuplicateId { id: 'a' })
+        );
+
+        let ids: String = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .chain("+-_".chars())
+            .collect();
+        assert_eq!(
+            ItemAlphabet::try_build_from_ids(&ids),
+            Err(ItemAlphabetError::TooManyIds { number_of_ids: 65 })
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use super::{item::Item, item_alphabet::ItemAlphabet};
+use std::{error, fmt};
+
+#[derive(Debug, PartialEq)]
+pub enum ItemSetError {
+    DifferentAlphabets,
+    ItemOfOtherAlphabet { id: char },
+}
+
+impl fmt::Display for ItemSetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ItemSetError::DifferentAlphabets => write!(
+                f,
+                "item set error, item sets of different alphabets cannot be combined"
+            ),
+            ItemSetError::ItemOfOtherAlphabet { id } => write!(
+                f,
+                "item set error, item '{}' is not of the alphabet of the item set",
+                id
+            ),
+        }
+    }
+}
+
+impl error::Error for ItemSetError {}
+
+// sets built from different alphabets cannot be combined, the same bit stands for other items
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ItemSet {
+    bits: u64,
+    alphabet: ItemAlphabet,
+}
+
+impl ItemSet {
+    pub fn new() -> Self {
+        Self::new_with_alphabet(&ItemAlphabet::default())
+    }
+
+    pub fn new_with_alphabet(alphabet: &ItemAlphabet) -> Self {
+        ItemSet {
+            bits: 0,
+            alphabet: alphabet.clone(),
+        }
+    }
+
+    pub fn try_build(items: &[Item]) -> Result<Self, ItemSetError> {
+        Self::try_build_with_alphabet(items, &ItemAlphabet::default())
+    }
+
+    pub fn try_build_with_alphabet(
+        items: &[Item],
+        alphabet: &ItemAlphabet,
+    ) -> Result<Self, ItemSetError> {
+        let mut item_set = ItemSet::new_with_alphabet(alphabet);
+        for item in items {
+            item_set.try_insert(item)?;
+        }
+        Ok(item_set)
+    }
+
+    pub fn get_alphabet(&self) -> &ItemAlphabet {
+        &self.alphabet
+    }
+
+    pub fn try_insert(&mut self, item: &Item) -> Result<(), ItemSetError> {
+        if !self.is_of_alphabet(item) {
+            return Err(ItemSetError::ItemOfOtherAlphabet { id: item.get_id() });
+        }
+
+        self.bits |= Self::get_bit(item);
+        Ok(())
+    }
+
+    // an item of another alphabet is never contained, even if its bit is set
+    pub fn contains(&self, item: &Item) -> bool {
+        self.is_of_alphabet(item) && self.bits & Self::get_bit(item) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn try_intersection(&self, other: &ItemSet) -> Result<ItemSet, ItemSetError> {
+        self.try_build_from_bits(other, self.bits & other.bits)
+    }
+
+    pub fn try_union(&self, other: &ItemSet) -> Result<ItemSet, ItemSetError> {
+        self.try_build_from_bits(other, self.bits | other.bits)
+    }
+
+    pub fn try_difference(&self, other: &ItemSet) -> Result<ItemSet, ItemSetError> {
+        self.try_build_from_bits(other, self.bits & !other.bits)
+    }
+
+    pub fn try_symmetric_difference(&self, other: &ItemSet) -> Result<ItemSet, ItemSetError> {
+        self.try_build_from_bits(other, self.bits ^ other.bits)
+    }
+
+    // an empty list has no alphabet of its own, so the default one is used
+    pub fn try_get_in_at_least(
+        item_sets: &[ItemSet],
+        number_of_item_sets: usize,
+    ) -> Result<ItemSet, ItemSetError> {
+        let Some(first_item_set) = item_sets.first() else {
+            return Ok(ItemSet::new());
+        };
+
+        for item_set in item_sets {
+            first_item_set.try_validate_same_alphabet(item_set)?;
+        }
+
+        let bits = (0..u64::BITS)
+            .filter(|bit_index| {
+                item_sets
+                    .iter()
+                    .filter(|item_set| item_set.bits & (1 << bit_index) != 0)
+                    .count()
+                    >= number_of_item_sets
+            })
+            .fold(0, |bits, bit_index| bits | (1 << bit_index));
+
+        Ok(ItemSet {
+            bits,
+            alphabet: first_item_set.alphabet.clone(),
+        })
+    }
+
+    // items are returned in the order of the alphabet
+    pub fn get_items(&self) -> Vec<Item> {
+        (0..self.alphabet.get_number_of_ids())
+            .filter(|index| self.bits & (1 << index) != 0)
+            .filter_map(|index| self.alphabet.get_id(index))
+            .filter_map(|id| Item::try_build_with_alphabet(id, &self.alphabet).ok())
+            .collect()
+    }
+
+    fn try_build_from_bits(&self, other: &ItemSet, bits: u64) -> Result<ItemSet, ItemSetError> {
+        self.try_validate_same_alphabet(other)?;
+
+        Ok(ItemSet {
+            bits,
+            alphabet: self.alphabet.clone(),
+        })
+    }
+
+    fn try_validate_same_alphabet(&self, other: &ItemSet) -> Result<(), ItemSetError> {
+        if self.alphabet.is_same(&other.alphabet) {
+            Ok(())
+        } else {
+            Err(ItemSetError::DifferentAlphabets)
+        }
+    }
+
+    // items only keep their index and priority, so the alphabet has to declare both for the id
+    fn is_of_alphabet(&self, item: &Item) -> bool {
+        self.alphabet.get_index(item.get_id()) == Some(item.get_index())
+            && self.alphabet.get_priority(item.get_index()) == Some(item.get_priority())
+    }
+
+    fn get_bit(item: &Item) -> u64 {
+        1 << item.get_index()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_item_set(ids: &str) -> ItemSet {
+        let items: Vec<Item> = ids.chars().map(|id| Item::try_build(id).unwrap()).collect();
+        ItemSet::try_build(&items).unwrap()
+    }
+
+    fn get_ids(item_set: &ItemSet) -> String {
+        item_set
+            .get_items()
+            .iter()
+            .map(|item| item.get_id())
+            .collect()
+    }
+
+    #[test]
+    fn build_empty_item_set() {
+        let item_set = build_item_set("");
+
+        assert!(item_set.is_empty());
+        assert_eq!(item_set.len(), 0);
+        assert_eq!(item_set, ItemSet::new());
+    }
+
+    #[test]
+    fn build_item_set_with_duplicates() {
+        let item_set = build_item_set("ZaZbaA");
+
+        assert_eq!(item_set.len(), 4);
+        assert!(item_set.contains(&Item::try_build('Z').unwrap()));
+        assert!(!item_set.contains(&Item::try_build('z').unwrap()));
+        assert_eq!(get_ids(&item_set), "abAZ");
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let item_set_1 = build_item_set("abcXY");
+        let item_set_2 = build_item_set("bcdYZ");
+
+        assert_eq!(
+            get_ids(&item_set_1.try_intersection(&item_set_2).unwrap()),
+            "bcY"
+        );
+        assert_eq!(
+            get_ids(&item_set_1.try_union(&item_set_2).unwrap()),
+            "abcdXYZ"
+        );
+        assert_eq!(
+            get_ids(&item_set_1.try_difference(&item_set_2).unwrap()),
+            "aX"
+        );
+        assert_eq!(
+            get_ids(&item_set_1.try_symmetric_difference(&item_set_2).unwrap()),
+            "adXZ"
+        );
+    }
+
+    #[test]
+    fn test_items_in_at_least_some_sets() {
+        let item_sets = [
+            build_item_set("aXbY"),
+            build_item_set("abcd"),
+            build_item_set("aXcZ"),
+        ];
+
+        let get_ids_in_at_least = |number_of_item_sets| {
+            get_ids(&ItemSet::try_get_in_at_least(&item_sets, number_of_item_sets).unwrap())
+        };
+
+        assert_eq!(get_ids_in_at_least(1), "abcdXYZ");
+        assert_eq!(get_ids_in_at_least(2), "abcX");
+        assert_eq!(get_ids_in_at_least(3), "a");
+        assert!(get_ids_in_at_least(4).is_empty());
+        assert_eq!(ItemSet::try_get_in_at_least(&[], 1), Ok(ItemSet::new()));
+    }
+
+    #[test]
+    fn test_items_of_other_alphabet() {
+        let alphabet = ItemAlphabet::try_build_from_ids("Zz9").unwrap();
+        let items: Vec<Item> = "9Z9"
+            .chars()
+            .map(|id| Item::try_build_with_alphabet(id, &alphabet).unwrap())
+            .collect();
+
+        let item_set = ItemSet::try_build_with_alphabet(&items, &alphabet).unwrap();
+        let ids: String = item_set
+            .get_items()
+            .iter()
+            .map(|item| item.get_id())
+            .collect();
+
+        assert_eq!(ids, "Z9");
+        assert_eq!(item_set.get_alphabet(), &alphabet);
+    }
+
+    #[test]
+    fn test_combine_item_sets_of_other_alphabets() {
+        let alphabet = ItemAlphabet::try_build_from_ids("abc").unwrap();
+        let item = Item::try_build_with_alphabet('a', &alphabet).unwrap();
+        let item_set = build_item_set("a");
+        let other_item_set = ItemSet::try_build_with_alphabet(&[item], &alphabet).unwrap();
+
+        assert_eq!(
+            item_set.try_intersection(&other_item_set),
+            Err(ItemSetError::DifferentAlphabets)
+        );
+        assert_eq!(
+            item_set.try_union(&other_item_set),
+            Err(ItemSetError::DifferentAlphabets)
+        );
+        assert_eq!(
+            item_set.try_difference(&other_item_set),
+            Err(ItemSetError::DifferentAlphabets)
+        );
+        assert_eq!(
+            item_set.try_symmetric_difference(&other_item_set),
+            Err(ItemSetError::DifferentAlphabets)
+        );
+        assert_eq!(
+            ItemSet::try_get_in_at_least(&[item_set, other_item_set], 1),
+            Err(ItemSetError::DifferentAlphabets)
+        );
+    }
+
+    #[test]
+    fn test_items_of_other_alphabets() {
+        let alphabet = ItemAlphabet::try_build_from_ids("Za").unwrap();
+        let other_item = Item::try_build_with_alphabet('a', &alphabet).unwrap();
+        let mut item_set = build_item_set("ab");
+
+        // 'a' has the index 1 in the other alphabet, which is 'b' in the default one
+        assert!(!item_set.contains(&other_item));
+        assert_eq!(
+            item_set.try_insert(&other_item),
+            Err(ItemSetError::ItemOfOtherAlphabet { id: 'a' })
+        );
+        assert_eq!(
+            ItemSet::try_build(&[other_item]),
+            Err(ItemSetError::ItemOfOtherAlphabet { id: 'a' })
+        );
+        assert_eq!(get_ids(&item_set), "ab");
+    }
+
+    #[test]
+    fn test_all_items() {
+        let ids: String = ('a'..='z').chain('A'..='Z').collect();
+        let item_set = build_item_set(&ids);
+
+        assert_eq!(item_set.len(), 52);
+        assert_eq!(get_ids(&item_set), ids);
+    }
+}